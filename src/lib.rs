@@ -1,4 +1,14 @@
-//! Currently only contains [scope functions](ScopeFunctions).
+//! Kotlin-like APIs for Rust, mostly as extension traits implemented for std types.
+//!
+//! - [Scope functions](ScopeFunctions) such as `using`, `also` and `apply`, with variants for references, tuples,
+//!   [`Result`](ResultScope) and [`Option`](OptionScope), and standalone counterparts such as [`with`] and [`run`]
+//! - Kotlin's collection functions for [iterators](IteratorExt), [slices](SliceExt), [vectors](VecExt),
+//!   [maps](MapExt) and [sets](SetExt), and lazy [sequences](Sequence)
+//! - Kotlin's [string functions](StringExt), such as case-insensitive comparisons and number parsing
+//! - Helpers for [`Option`](OptionExt), [`Result`](ResultExt), [locks](MutexExt) and [mutable references](MutExt)
+//! - Macros for piping ([`pipe!`]), null safety ([`elvis!`], [`safe!`]) and preconditions ([`require!`], [`check!`])
+//!
+//! Import the traits used, or everything with `use kust::*`.
 
 mod modules;
 pub use modules::*;
@@ -0,0 +1,127 @@
+use std::future::Future;
+
+/// Asynchronous counterparts of the [scope functions](crate::ScopeFunctions), accepting async closures.
+///
+/// Requires the `async` feature.
+pub trait AsyncScopeFunctions {
+    /// Calls the specified async function with this value as an argument and returns the result of the function.
+    ///
+    /// This is the async counterpart of [`using`](crate::ScopeFunctions::using).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn block_on<F: std::future::Future>(f: F) -> F::Output {
+    /// #     let mut f = std::pin::pin!(f);
+    /// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    /// #     loop {
+    /// #         if let std::task::Poll::Ready(v) = f.as_mut().poll(&mut cx) { return v; }
+    /// #     }
+    /// # }
+    /// use kust::AsyncScopeFunctions;
+    ///
+    /// async fn fetch_name(id: u32) -> String {
+    ///     format!("user-{id}")
+    /// }
+    ///
+    /// block_on(async {
+    ///     let greeting = 7.using_async(async |id| format!("Hi, {}!", fetch_name(id).await)).await;
+    ///     assert_eq!(greeting, "Hi, user-7!");
+    /// });
+    /// ```
+    #[inline]
+    fn using_async<F, R>(self, f: F) -> impl Future<Output = R>
+    where
+        Self: Sized,
+        F: AsyncFnOnce(Self) -> R,
+    {
+        f(self)
+    }
+
+    /// Calls the specified async function with an immutable reference to `self` and returns `self`.
+    ///
+    /// This is the async counterpart of [`also`](crate::ScopeFunctions::also).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn block_on<F: std::future::Future>(f: F) -> F::Output {
+    /// #     let mut f = std::pin::pin!(f);
+    /// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    /// #     loop {
+    /// #         if let std::task::Poll::Ready(v) = f.as_mut().poll(&mut cx) { return v; }
+    /// #     }
+    /// # }
+    /// use std::cell::RefCell;
+    /// use kust::AsyncScopeFunctions;
+    ///
+    /// let log = RefCell::new(Vec::new());
+    ///
+    /// async fn record(log: &RefCell<Vec<String>>, entry: String) {
+    ///     log.borrow_mut().push(entry);
+    /// }
+    ///
+    /// block_on(async {
+    ///     let value = (6 * 7).also_async(async |v| record(&log, format!("computed {v}")).await).await;
+    ///     assert_eq!(value, 42);
+    /// });
+    ///
+    /// assert_eq!(log.into_inner(), ["computed 42"]);
+    /// ```
+    #[inline]
+    fn also_async<F, R>(self, f: F) -> impl Future<Output = Self>
+    where
+        Self: Sized,
+        F: AsyncFnOnce(&Self) -> R,
+    {
+        async move {
+            f(&self).await;
+            self
+        }
+    }
+
+    /// Calls the specified async function with a mutable reference to `self` and returns `self`.
+    ///
+    /// This is the async counterpart of [`apply`](crate::ScopeFunctions::apply), for when configuring a value
+    /// requires an `.await`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn block_on<F: std::future::Future>(f: F) -> F::Output {
+    /// #     let mut f = std::pin::pin!(f);
+    /// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    /// #     loop {
+    /// #         if let std::task::Poll::Ready(v) = f.as_mut().poll(&mut cx) { return v; }
+    /// #     }
+    /// # }
+    /// use kust::AsyncScopeFunctions;
+    ///
+    /// #[derive(Default)]
+    /// struct Session {
+    ///     token: String,
+    /// }
+    ///
+    /// async fn login(user: &str) -> String {
+    ///     format!("token-for-{user}")
+    /// }
+    ///
+    /// block_on(async {
+    ///     let session = Session::default().apply_async(async |s| s.token = login("mike").await).await;
+    ///     assert_eq!(session.token, "token-for-mike");
+    /// });
+    /// ```
+    #[inline]
+    fn apply_async<F, R>(mut self, f: F) -> impl Future<Output = Self>
+    where
+        Self: Sized,
+        F: AsyncFnOnce(&mut Self) -> R,
+    {
+        async move {
+            f(&mut self).await;
+            self
+        }
+    }
+}
+
+impl<T> AsyncScopeFunctions for T {}
@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Kotlin-style extensions for collections: [`Vec`], [`VecDeque`], [`String`], the standard maps and sets, and
+/// references to slices and string slices.
+pub trait CollectionExt: Sized {
+    /// The type returned by [`if_empty`](CollectionExt::if_empty): the collection itself, except for string slices,
+    /// for which it is a [`Cow`] so that the fallback may be an owned [`String`].
+    type Fallback;
+
+    /// Returns `true` if the collection is not empty.
+    ///
+    /// This is Kotlin's `isNotEmpty`, and reads better than `!x.is_empty()` at the end of a long expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::CollectionExt;
+    ///
+    /// let errors: Vec<&str> = Vec::new();
+    ///
+    /// assert!(!errors.is_not_empty());
+    /// assert!("text".is_not_empty());
+    /// ```
+    #[must_use]
+    fn is_not_empty(&self) -> bool;
+
+    /// Returns this collection if it is not empty, or the value returned by the specified function otherwise.
+    ///
+    /// This is Kotlin's `ifEmpty`. The function may return anything convertible to the [`Fallback`] type, such as a
+    /// borrowed or an owned string for a string slice, like with [`StringExt::if_blank`](crate::StringExt::if_blank).
+    ///
+    /// [`Fallback`]: CollectionExt::Fallback
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::CollectionExt;
+    ///
+    /// let tags: Vec<&str> = Vec::new();
+    ///
+    /// assert_eq!(tags.if_empty(|| vec!["untagged"]), ["untagged"]);
+    /// assert_eq!("".if_empty(|| "(none)"), "(none)");
+    /// assert_eq!(String::from("Mike").if_empty(|| "anonymous".to_string()), "Mike");
+    ///
+    /// let id = 7;
+    /// assert_eq!("".if_empty(|| format!("user-{id}")), "user-7");
+    /// ```
+    #[must_use]
+    fn if_empty<F, D>(self, default: F) -> Self::Fallback
+    where
+        F: FnOnce() -> D,
+        D: Into<Self::Fallback>;
+}
+
+macro_rules! impl_collection_ext {
+    ($(impl $(<$($generic:tt),*>)? for $ty:ty => $fallback:ty;)*) => {$(
+        impl$(<$($generic),*>)? CollectionExt for $ty {
+            type Fallback = $fallback;
+
+            #[inline]
+            fn is_not_empty(&self) -> bool {
+                !self.is_empty()
+            }
+
+            #[inline]
+            fn if_empty<F, D>(self, default: F) -> Self::Fallback
+            where
+                F: FnOnce() -> D,
+                D: Into<Self::Fallback>,
+            {
+                if self.is_empty() { default().into() } else { self.into() }
+            }
+        }
+    )*};
+}
+
+impl_collection_ext! {
+    impl<T> for Vec<T> => Self;
+    impl<T> for VecDeque<T> => Self;
+    impl for String => Self;
+    impl<K, V, S> for HashMap<K, V, S> => Self;
+    impl<T, S> for HashSet<T, S> => Self;
+    impl<K, V> for BTreeMap<K, V> => Self;
+    impl<T> for BTreeSet<T> => Self;
+    impl<'a, T> for &'a [T] => Self;
+    impl<'a> for &'a str => Cow<'a, str>;
+}
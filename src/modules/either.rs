@@ -0,0 +1,45 @@
+/// A value of one of two types, such as the result of the function passed to
+/// [`partition_map`](crate::IteratorExt::partition_map).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    /// A value of the left type.
+    Left(L),
+    /// A value of the right type.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Returns `true` if this is a [`Left`](Either::Left) value.
+    #[inline]
+    #[must_use]
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Returns `true` if this is a [`Right`](Either::Right) value.
+    #[inline]
+    #[must_use]
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Returns the left value, or [`None`] if this is a [`Right`](Either::Right) value.
+    #[inline]
+    #[must_use]
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(left) => Some(left),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, or [`None`] if this is a [`Left`](Either::Left) value.
+    #[inline]
+    #[must_use]
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(right) => Some(right),
+        }
+    }
+}
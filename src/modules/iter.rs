@@ -0,0 +1,2872 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Write};
+use std::hash::{BuildHasher, Hash};
+use std::iter::{Flatten, Fuse, Inspect, Peekable, Skip, Sum, Take};
+use std::ops::Add;
+use std::vec;
+
+use crate::Either;
+
+/// Kotlin-style extensions for iterators.
+pub trait IteratorExt: Iterator {
+    /// Groups the elements by the key returned by the specified function, returning a map from each key to the
+    /// elements with that key, in iteration order.
+    ///
+    /// This is Kotlin's `groupBy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+    /// let by_letter = words.into_iter().group_by(|w| w.chars().next().unwrap());
+    ///
+    /// assert_eq!(by_letter[&'a'], ["apple", "avocado"]);
+    /// assert_eq!(by_letter[&'b'], ["banana", "blueberry"]);
+    /// assert_eq!(by_letter[&'c'], ["cherry"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn group_by<K, F>(self, key: F) -> HashMap<K, Vec<Self::Item>>
+    where
+        Self: Sized,
+        K: Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut map = HashMap::new();
+        self.group_by_to(&mut map, key);
+        map
+    }
+
+    /// Groups the elements by the key returned by the specified function, appending them to the given map.
+    ///
+    /// See [`group_by`](IteratorExt::group_by).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let mut by_parity = HashMap::from([(true, vec![0])]);
+    /// (1..=5).group_by_to(&mut by_parity, |n| n % 2 == 0);
+    ///
+    /// assert_eq!(by_parity[&true], [0, 2, 4]);
+    /// assert_eq!(by_parity[&false], [1, 3, 5]);
+    /// ```
+    fn group_by_to<K, F, S>(self, map: &mut HashMap<K, Vec<Self::Item>, S>, mut key: F)
+    where
+        Self: Sized,
+        K: Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+        S: BuildHasher,
+    {
+        for item in self {
+            map.entry(key(&item)).or_default().push(item);
+        }
+    }
+
+    /// Returns a map from the key returned by the specified function to each element.
+    ///
+    /// If several elements have the same key, the last one is kept. This is Kotlin's `associateBy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// struct User {
+    ///     id: u32,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let users = [User { id: 7, name: "Mike" }, User { id: 3, name: "Linda" }];
+    /// let by_id = users.into_iter().associate_by(|u| u.id);
+    ///
+    /// assert_eq!(by_id[&3].name, "Linda");
+    /// assert_eq!(by_id[&7].name, "Mike");
+    /// ```
+    #[inline]
+    #[must_use]
+    fn associate_by<K, F>(self, key: F) -> HashMap<K, Self::Item>
+    where
+        Self: Sized,
+        K: Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut map = HashMap::new();
+        self.associate_by_to(&mut map, key);
+        map
+    }
+
+    /// Inserts each element into the given map, with the key returned by the specified function.
+    ///
+    /// See [`associate_by`](IteratorExt::associate_by).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let mut by_length = HashMap::from([(1, "a")]);
+    /// ["bb", "ccc", "dd"].into_iter().associate_by_to(&mut by_length, |s| s.len());
+    ///
+    /// assert_eq!(by_length, HashMap::from([(1, "a"), (2, "dd"), (3, "ccc")]));
+    /// ```
+    fn associate_by_to<K, F, S>(self, map: &mut HashMap<K, Self::Item, S>, mut key: F)
+    where
+        Self: Sized,
+        K: Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+        S: BuildHasher,
+    {
+        map.extend(self.map(|item| (key(&item), item)));
+    }
+
+    /// Returns a map from each element to the value returned by the specified function.
+    ///
+    /// If an element occurs several times, the value of the last occurrence is kept. This is Kotlin's
+    /// `associateWith`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let lengths = ["one", "three", "five"].into_iter().associate_with(|w| w.len());
+    ///
+    /// assert_eq!(lengths["three"], 5);
+    /// assert_eq!(lengths["five"], 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn associate_with<V, F>(self, value: F) -> HashMap<Self::Item, V>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq,
+        F: FnMut(&Self::Item) -> V,
+    {
+        let mut map = HashMap::new();
+        self.associate_with_to(&mut map, value);
+        map
+    }
+
+    /// Inserts each element into the given map as a key, with the value returned by the specified function.
+    ///
+    /// See [`associate_with`](IteratorExt::associate_with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let mut squares = HashMap::new();
+    /// (1..=3).associate_with_to(&mut squares, |n| n * n);
+    /// (3..=4).associate_with_to(&mut squares, |n| n * n);
+    ///
+    /// assert_eq!(squares, HashMap::from([(1, 1), (2, 4), (3, 9), (4, 16)]));
+    /// ```
+    fn associate_with_to<V, F, S>(self, map: &mut HashMap<Self::Item, V, S>, mut value: F)
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq,
+        F: FnMut(&Self::Item) -> V,
+        S: BuildHasher,
+    {
+        map.extend(self.map(|item| {
+            let value = value(&item);
+            (item, value)
+        }));
+    }
+
+    /// Returns an iterator over chunks of the given size, each collected into a `Vec`.
+    ///
+    /// The last chunk may be smaller than `size`. This is Kotlin's `chunked`, and works on any iterator, unlike
+    /// [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let chunks: Vec<_> = (1..=7).chunked(3).collect();
+    ///
+    /// assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    /// ```
+    #[inline]
+    fn chunked(self, size: usize) -> Chunked<Self>
+    where
+        Self: Sized,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunked { iter: self, size }
+    }
+
+    /// Returns an iterator over the results of calling the specified function with each chunk of the given size.
+    ///
+    /// Each chunk is passed to the function as an iterator, so computing an aggregate per chunk doesn't allocate.
+    /// Elements of the chunk not consumed by the function are skipped. The last chunk may be smaller than `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let sums: Vec<i32> = (1..=7).chunked_map(3, |chunk| chunk.sum()).collect();
+    /// assert_eq!(sums, [6, 15, 7]);
+    ///
+    /// let firsts: Vec<_> = "abcdefg".chars().chunked_map(2, |mut chunk| chunk.next().unwrap()).collect();
+    /// assert_eq!(firsts, ['a', 'c', 'e', 'g']);
+    /// ```
+    #[inline]
+    fn chunked_map<R, F>(self, size: usize, f: F) -> ChunkedMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Chunk<'_, Self>) -> R,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunkedMap { iter: self.peekable(), size, f }
+    }
+
+    /// Returns an iterator over sliding windows of the given size, each collected into a `Vec`, starting every `step`
+    /// elements.
+    ///
+    /// If `partial` is `true`, the windows at the end, which are smaller than `size`, are also returned, as long as
+    /// they are not empty. This is Kotlin's `windowed`, and works on any iterator. As consecutive windows may overlap,
+    /// the elements must be [`Clone`]. For slices, see [`SliceExt::windowed`](crate::SliceExt::windowed), which
+    /// yields sub-slices instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `step` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let windows: Vec<_> = (1..=5).windowed(3, 1, false).collect();
+    /// assert_eq!(windows, [vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+    ///
+    /// let windows: Vec<_> = (1..=5).windowed(3, 2, true).collect();
+    /// assert_eq!(windows, [vec![1, 2, 3], vec![3, 4, 5], vec![5]]);
+    ///
+    /// // Moving average
+    /// let averages: Vec<f64> = [2.0, 4.0, 6.0, 8.0]
+    ///     .into_iter()
+    ///     .windowed(2, 1, false)
+    ///     .map(|w| w.iter().sum::<f64>() / 2.0)
+    ///     .collect();
+    /// assert_eq!(averages, [3.0, 5.0, 7.0]);
+    /// ```
+    #[inline]
+    fn windowed(self, size: usize, step: usize, partial: bool) -> Windowed<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        assert!(size != 0 && step != 0, "window size and step must be non-zero");
+        // The size may be much larger than the iterator, so only reserve what is known to be needed.
+        let buffer = VecDeque::with_capacity(size.min(self.size_hint().0));
+        Windowed { iter: self.fuse(), buffer, size, step, partial, started: false }
+    }
+
+    /// Returns an iterator over pairs of consecutive elements.
+    ///
+    /// Each element except the first and the last appears in two pairs, so the elements must be [`Clone`]. This is
+    /// Kotlin's `zipWithNext`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let pairs: Vec<_> = "abcd".chars().zip_with_next().collect();
+    ///
+    /// assert_eq!(pairs, [('a', 'b'), ('b', 'c'), ('c', 'd')]);
+    /// ```
+    #[inline]
+    fn zip_with_next(self) -> ZipWithNext<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        ZipWithNext { iter: self, previous: None }
+    }
+
+    /// Returns an iterator over the results of calling the specified function with each pair of consecutive elements.
+    ///
+    /// The function receives references, so the elements don't have to be [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let readings = [10, 12, 9, 15];
+    ///
+    /// let deltas: Vec<i32> = readings.into_iter().zip_with_next_map(|a, b| b - a).collect();
+    /// assert_eq!(deltas, [2, -3, 6]);
+    ///
+    /// let increasing = readings.into_iter().zip_with_next_map(|a, b| a < b).all(|up| up);
+    /// assert!(!increasing);
+    /// ```
+    #[inline]
+    fn zip_with_next_map<R, F>(self, f: F) -> ZipWithNextMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+    {
+        ZipWithNextMap { iter: self, previous: None, f }
+    }
+
+    /// Returns an iterator over the distinct elements, keeping the first occurrence of each, in iteration order.
+    ///
+    /// The elements seen so far are tracked in a [`HashSet`], so they must be [`Clone`]. Unlike [`Vec::dedup`], this
+    /// also removes non-consecutive duplicates. This is Kotlin's `distinct`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let unique: Vec<_> = [3, 1, 3, 2, 1].into_iter().distinct().collect();
+    ///
+    /// assert_eq!(unique, [3, 1, 2]);
+    /// ```
+    #[inline]
+    fn distinct(self) -> Distinct<Self>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq + Clone,
+    {
+        Distinct { iter: self, seen: HashSet::new() }
+    }
+
+    /// Returns an iterator over the elements with distinct keys, as returned by the specified function, keeping the
+    /// first element with each key, in iteration order.
+    ///
+    /// This is Kotlin's `distinctBy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let words = ["apple", "Avocado", "banana", "APRICOT", "blueberry"];
+    /// let by_letter: Vec<_> = words
+    ///     .into_iter()
+    ///     .distinct_by(|w| w.chars().next().unwrap().to_ascii_lowercase())
+    ///     .collect();
+    ///
+    /// assert_eq!(by_letter, ["apple", "banana"]);
+    /// ```
+    #[inline]
+    fn distinct_by<K, F>(self, key: F) -> DistinctBy<Self, K, F>
+    where
+        Self: Sized,
+        K: Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        DistinctBy { iter: self, seen: HashSet::new(), key }
+    }
+
+    /// Collects and sorts the elements, returning an iterator over them in ascending order.
+    ///
+    /// The sort is stable. This is Kotlin's `sorted`, allowing sorting in the middle of a chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let top: Vec<_> = [5, 3, 9, 1].into_iter().sorted().take(2).collect();
+    ///
+    /// assert_eq!(top, [1, 3]);
+    /// ```
+    #[inline]
+    fn sorted(self) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.sort();
+        items.into_iter()
+    }
+
+    /// Collects and sorts the elements, returning an iterator over them in descending order.
+    ///
+    /// The sort is stable. This is Kotlin's `sortedDescending`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let sorted: Vec<_> = "kotlin".chars().sorted_desc().collect();
+    ///
+    /// assert_eq!(sorted, ['t', 'o', 'n', 'l', 'k', 'i']);
+    /// ```
+    #[inline]
+    fn sorted_desc(self) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.sorted_by(|a, b| b.cmp(a))
+    }
+
+    /// Collects and sorts the elements with the specified comparator, returning an iterator over them.
+    ///
+    /// The sort is stable. This is Kotlin's `sortedWith`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let sorted: Vec<_> = [2.5, -1.0, 0.5].into_iter().sorted_by(f64::total_cmp).collect();
+    ///
+    /// assert_eq!(sorted, [-1.0, 0.5, 2.5]);
+    /// ```
+    #[inline]
+    fn sorted_by<F>(self, compare: F) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.sort_by(compare);
+        items.into_iter()
+    }
+
+    /// Collects and sorts the elements with the specified comparator, returning an iterator over them in reverse
+    /// order.
+    ///
+    /// The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let sorted: Vec<_> = [2.5, -1.0, 0.5].into_iter().sorted_by_desc(f64::total_cmp).collect();
+    ///
+    /// assert_eq!(sorted, [2.5, 0.5, -1.0]);
+    /// ```
+    #[inline]
+    fn sorted_by_desc<F>(self, mut compare: F) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.sorted_by(|a, b| compare(b, a))
+    }
+
+    /// Collects and sorts the elements by the key returned by the specified function, returning an iterator over them.
+    ///
+    /// The sort is stable. This is Kotlin's `sortedBy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let by_length: Vec<_> = ["banana", "fig", "apple"].into_iter().sorted_by_key(|s| s.len()).collect();
+    ///
+    /// assert_eq!(by_length, ["fig", "apple", "banana"]);
+    /// ```
+    #[inline]
+    fn sorted_by_key<K, F>(self, key: F) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.sort_by_key(key);
+        items.into_iter()
+    }
+
+    /// Collects and sorts the elements by the key returned by the specified function, in descending order, returning
+    /// an iterator over them.
+    ///
+    /// The sort is stable. This is Kotlin's `sortedByDescending`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let scores = [("Mike", 72), ("Linda", 95), ("Anna", 72)];
+    /// let ranking: Vec<_> = scores
+    ///     .into_iter()
+    ///     .sorted_by_key_desc(|&(_, score)| score)
+    ///     .map(|(name, _)| name)
+    ///     .collect();
+    ///
+    /// assert_eq!(ranking, ["Linda", "Mike", "Anna"]);
+    /// ```
+    #[inline]
+    fn sorted_by_key_desc<K, F>(self, mut key: F) -> vec::IntoIter<Self::Item>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.sorted_by(|a, b| key(b).cmp(&key(a)))
+    }
+
+    /// Returns the sum of the values returned by the specified function for each element.
+    ///
+    /// This is Kotlin's `sumOf`. It avoids a `map` followed by a turbofished `sum::<T>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// struct Item { price: u32 }
+    ///
+    /// let cart = [Item { price: 15 }, Item { price: 7 }, Item { price: 20 }];
+    ///
+    /// assert_eq!(cart.iter().sum_of(|item| item.price), 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn sum_of<S, F>(self, f: F) -> S
+    where
+        Self: Sized,
+        S: Sum<S>,
+        F: FnMut(Self::Item) -> S,
+    {
+        self.map(f).sum()
+    }
+
+    /// Returns the average of the values returned by the specified function for each element, or [`None`] if the
+    /// iterator is empty.
+    ///
+    /// The sum and the count are computed in a single pass. The function can return any primitive number, see
+    /// [`ToF64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let scores = [("Mike", 7u8), ("Linda", 9), ("Anna", 8)];
+    ///
+    /// assert_eq!(scores.iter().average_of(|&(_, score)| score), Some(8.0));
+    /// assert_eq!(scores[..0].iter().average_of(|&(_, score)| score), None);
+    ///
+    /// let sizes: [u64; 3] = [1024, 2048, 4096];
+    /// assert_eq!(sizes.iter().average_of(|&size| size), Some(2389.3333333333335));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn average_of<N, F>(self, mut f: F) -> Option<f64>
+    where
+        Self: Sized,
+        N: ToF64,
+        F: FnMut(Self::Item) -> N,
+    {
+        let (sum, count) = self.fold((0.0, 0usize), |(sum, count), item| (sum + f(item).to_f64(), count + 1));
+        (count != 0).then(|| sum / count as f64)
+    }
+
+    /// Returns the minimum and maximum elements in a single pass, or [`None`] if the iterator is empty.
+    ///
+    /// Like [`Iterator::min`] and [`Iterator::max`], the first minimum and the last maximum are returned when several
+    /// elements are equal. A single element is both the minimum and the maximum, hence the [`Clone`] bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// assert_eq!([3, 1, 4, 1, 5].into_iter().min_max(), Some((1, 5)));
+    /// assert_eq!([7].into_iter().min_max(), Some((7, 7)));
+    /// assert_eq!(std::iter::empty::<i32>().min_max(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn min_max(self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.min_max_by(Ord::cmp)
+    }
+
+    /// Returns the elements with the minimum and maximum keys returned by the specified function, in a single pass, or
+    /// [`None`] if the iterator is empty.
+    ///
+    /// The key function is called once per element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let words = ["kiwi", "fig", "banana", "plum"];
+    ///
+    /// assert_eq!(words.into_iter().min_max_by_key(|w| w.len()), Some(("fig", "banana")));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn min_max_by_key<K, F>(self, mut key: F) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        K: Ord + Clone,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(|item| (key(&item), item)).min_max_by(|(a, _), (b, _)| a.cmp(b)).map(|((_, min), (_, max))| (min, max))
+    }
+
+    /// Returns the minimum and maximum elements with respect to the specified comparator, in a single pass, or
+    /// [`None`] if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let readings = [2.5, -1.0, 7.25, 0.0];
+    ///
+    /// assert_eq!(readings.into_iter().min_max_by(f64::total_cmp), Some((-1.0, 7.25)));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn min_max_by<F>(mut self, mut compare: F) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let first = self.next()?;
+        Some(self.fold((first.clone(), first), |(min, max), item| {
+            if compare(&item, &min).is_lt() {
+                (item, max)
+            } else if compare(&item, &max).is_ge() {
+                (min, item)
+            } else {
+                (min, max)
+            }
+        }))
+    }
+
+    /// Groups the elements by the key returned by the specified function, returning a [`Grouping`] on which
+    /// per-key aggregations can be computed.
+    ///
+    /// Unlike [`group_by`](IteratorExt::group_by), no per-key [`Vec`] is materialized - each operation on the
+    /// grouping is computed in a single pass. This is Kotlin's `groupingBy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let counts = "mississippi".chars().grouping_by(|&c| c).each_count();
+    ///
+    /// assert_eq!(counts, HashMap::from([('m', 1), ('i', 4), ('s', 4), ('p', 2)]));
+    /// ```
+    #[inline]
+    fn grouping_by<K, F>(self, key: F) -> Grouping<Self, F>
+    where
+        Self: Sized,
+        K: Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        Grouping { iter: self, key }
+    }
+
+    /// Joins the elements into a [`String`], separated by `separator`, returning a [`JoinToString`] builder that
+    /// allows configuring a prefix, a postfix, a limit and a per-element transform.
+    ///
+    /// Call [`build`](JoinToString::build) to produce the string. This is Kotlin's `joinToString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().join_to_string(", ").build(), "1, 2, 3");
+    ///
+    /// let log = (1..=100)
+    ///     .join_to_string(" | ")
+    ///     .prefix("[")
+    ///     .postfix("]")
+    ///     .limit(3)
+    ///     .transform(|n| format!("#{n}"))
+    ///     .build();
+    ///
+    /// assert_eq!(log, "[#1 | #2 | #3 | ...]");
+    /// ```
+    #[inline]
+    fn join_to_string(self, separator: &str) -> JoinToString<'_, Self>
+    where
+        Self: Sized,
+    {
+        JoinToString {
+            iter: self,
+            separator,
+            prefix: "",
+            postfix: "",
+            limit: None,
+            truncated: "...",
+            transform: |item| item,
+        }
+    }
+
+    /// Returns `true` if no element matches the specified predicate.
+    ///
+    /// This is the readable negation of [`Iterator::any`], and Kotlin's `none`. It short-circuits on the first
+    /// matching element, and returns `true` for an empty iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let temperatures = [18, 21, 24, 19];
+    ///
+    /// assert!(temperatures.iter().none(|&t| t > 30));
+    /// assert!(!temperatures.iter().none(|&t| t > 20));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn none<F>(&mut self, predicate: F) -> bool
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> bool,
+    {
+        !self.any(predicate)
+    }
+
+    /// Returns the only element, or a [`SingleError`] describing whether the iterator is empty or has more than one
+    /// element.
+    ///
+    /// At most two elements are consumed. This is Kotlin's `single`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::{IteratorExt, SingleError};
+    ///
+    /// assert_eq!([42].into_iter().single(), Ok(42));
+    /// assert_eq!(std::iter::empty::<i32>().single(), Err(SingleError::Empty));
+    /// assert_eq!([1, 2].into_iter().single(), Err(SingleError::MoreThanOne));
+    /// ```
+    #[inline]
+    fn single(mut self) -> Result<Self::Item, SingleError>
+    where
+        Self: Sized,
+    {
+        match (self.next(), self.next()) {
+            (Some(item), None) => Ok(item),
+            (None, _) => Err(SingleError::Empty),
+            (Some(_), Some(_)) => Err(SingleError::MoreThanOne),
+        }
+    }
+
+    /// Returns the only element matching the specified predicate, or a [`SingleError`] if there are none or more than
+    /// one.
+    ///
+    /// This is equivalent to `filter(predicate).single()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::{IteratorExt, SingleError};
+    ///
+    /// let users = [("admin", true), ("guest", false), ("mike", false)];
+    ///
+    /// assert_eq!(users.iter().single_by(|(_, admin)| *admin), Ok(&("admin", true)));
+    /// assert_eq!(users.iter().single_by(|(_, admin)| !*admin), Err(SingleError::MoreThanOne));
+    /// ```
+    #[inline]
+    fn single_by<P>(self, predicate: P) -> Result<Self::Item, SingleError>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.filter(predicate).single()
+    }
+
+    /// Returns the only element, or [`None`] if the iterator is empty or has more than one element.
+    ///
+    /// This is Kotlin's `singleOrNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// assert_eq!("x".chars().single_or_none(), Some('x'));
+    /// assert_eq!("xy".chars().single_or_none(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn single_or_none(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        self.single().ok()
+    }
+
+    /// Returns the only element matching the specified predicate, or [`None`] if there are none or more than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// assert_eq!((1..10).single_or_none_by(|n| n % 7 == 0), Some(7));
+    /// assert_eq!((1..10).single_or_none_by(|n| n % 3 == 0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn single_or_none_by<P>(self, predicate: P) -> Option<Self::Item>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.single_by(predicate).ok()
+    }
+
+    /// Returns an iterator over the last `n` elements.
+    ///
+    /// The elements are buffered in a ring buffer of up to `n` elements until the underlying iterator is exhausted.
+    /// See [`take_last_sized`](IteratorExt::take_last_sized) to skip the leading elements without buffering when the
+    /// length is known. This is Kotlin's `takeLast`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let last: Vec<_> = (1..=10).filter(|n| n % 2 == 1).take_last(2).collect();
+    ///
+    /// assert_eq!(last, [7, 9]);
+    /// assert_eq!([1, 2].iter().take_last(5).count(), 2);
+    /// ```
+    #[inline]
+    fn take_last(self, n: usize) -> TakeLast<Self>
+    where
+        Self: Sized,
+    {
+        TakeLast { iter: self, n, buffer: None }
+    }
+
+    /// Returns an iterator over the last `n` elements of an iterator of known length, skipping the leading elements.
+    ///
+    /// Unlike [`take_last`](IteratorExt::take_last), nothing is buffered: the leading elements are skipped with
+    /// [`nth`](Iterator::nth), which takes constant time for slice and [`Vec`] iterators, among others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let log = ["boot", "login", "error", "logout"];
+    /// let recent: Vec<_> = log.iter().take_last_sized(2).collect();
+    ///
+    /// assert_eq!(recent, [&"error", &"logout"]);
+    /// assert_eq!(log.iter().take_last_sized(10).count(), 4);
+    /// ```
+    #[inline]
+    fn take_last_sized(self, n: usize) -> Skip<Self>
+    where
+        Self: Sized + ExactSizeIterator,
+    {
+        let skipped = self.len().saturating_sub(n);
+        self.skip(skipped)
+    }
+
+    /// Returns an iterator over all elements except the last `n`.
+    ///
+    /// Up to `n` elements are buffered ahead in a ring buffer. See [`drop_last_sized`](IteratorExt::drop_last_sized)
+    /// to yield the elements directly when the length is known. This is Kotlin's `dropLast`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let lines = "header\nrow 1\nrow 2\nfooter";
+    /// let body: Vec<_> = lines.lines().skip(1).drop_last(1).collect();
+    ///
+    /// assert_eq!(body, ["row 1", "row 2"]);
+    /// ```
+    #[inline]
+    fn drop_last(self, n: usize) -> DropLast<Self>
+    where
+        Self: Sized,
+    {
+        DropLast { iter: self, n, buffer: VecDeque::new() }
+    }
+
+    /// Returns an iterator over all elements except the last `n` of an iterator of known length.
+    ///
+    /// Unlike [`drop_last`](IteratorExt::drop_last), nothing is buffered, and the remaining elements are never read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let path = ["usr", "local", "bin", "kust"];
+    /// let parent: Vec<_> = path.into_iter().drop_last_sized(1).collect();
+    ///
+    /// assert_eq!(parent, ["usr", "local", "bin"]);
+    /// assert_eq!(path.iter().drop_last_sized(10).count(), 0);
+    /// ```
+    #[inline]
+    fn drop_last_sized(self, n: usize) -> Take<Self>
+    where
+        Self: Sized + ExactSizeIterator,
+    {
+        let taken = self.len().saturating_sub(n);
+        self.take(taken)
+    }
+
+    /// Returns an iterator over the trailing elements that match the specified predicate, in their original order.
+    ///
+    /// The underlying iterator is consumed entirely when the first element is requested, buffering only the current
+    /// run of matching elements. This is Kotlin's `takeLastWhile`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let digits: String = "order-66-1138".chars().take_last_while(char::is_ascii_digit).collect();
+    ///
+    /// assert_eq!(digits, "1138");
+    /// ```
+    #[inline]
+    fn take_last_while<P>(self, predicate: P) -> TakeLastWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TakeLastWhile { iter: self, predicate, buffer: None }
+    }
+
+    /// Returns an iterator over all elements except the trailing ones that match the specified predicate.
+    ///
+    /// Runs of matching elements are buffered until a non-matching element shows they are not trailing. This is
+    /// Kotlin's `dropLastWhile`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let samples = [3, 0, 5, 0, 0];
+    /// let trimmed: Vec<_> = samples.into_iter().drop_last_while(|&s| s == 0).collect();
+    ///
+    /// assert_eq!(trimmed, [3, 0, 5]);
+    /// ```
+    #[inline]
+    fn drop_last_while<P>(self, predicate: P) -> DropLastWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        DropLastWhile { iter: self, predicate, pending: VecDeque::new(), ready: 0 }
+    }
+
+    /// Returns an iterator over the successive accumulated values of a fold, starting with `init`.
+    ///
+    /// The first element is always `init`, followed by the value after each element is accumulated. This is Kotlin's
+    /// `runningFold` (also `scan`), without the [`Option`]-based contract of [`Iterator::scan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let balances: Vec<_> = [50, -20, 35].into_iter().running_fold(100, |balance, tx| balance + tx).collect();
+    ///
+    /// assert_eq!(balances, [100, 150, 130, 165]);
+    /// ```
+    #[inline]
+    fn running_fold<R, F>(self, init: R, op: F) -> RunningFold<Self, R, F>
+    where
+        Self: Sized,
+        R: Clone,
+        F: FnMut(R, Self::Item) -> R,
+    {
+        RunningFold { iter: self, acc: Some(init), op, started: false }
+    }
+
+    /// Returns an iterator over the successive accumulated values of a reduction, starting with the first element.
+    ///
+    /// This is Kotlin's `runningReduce`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let maxima: Vec<_> = [3, 1, 4, 1, 5].into_iter().running_reduce(i32::max).collect();
+    ///
+    /// assert_eq!(maxima, [3, 3, 4, 4, 5]);
+    /// ```
+    #[inline]
+    fn running_reduce<F>(self, op: F) -> RunningReduce<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        RunningReduce { iter: self, acc: None, op }
+    }
+
+    /// Folds the elements into an accumulator, passing the index of each element to the operation.
+    ///
+    /// This is Kotlin's `foldIndexed`, and avoids destructuring `enumerate` tuples inside the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let weighted = [4, 2, 7].into_iter().fold_indexed(0, |i, sum, n| sum + (i + 1) * n);
+    ///
+    /// assert_eq!(weighted, 4 + 2 * 2 + 3 * 7);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn fold_indexed<R, F>(self, init: R, mut op: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(usize, R, Self::Item) -> R,
+    {
+        self.enumerate().fold(init, |acc, (index, item)| op(index, acc, item))
+    }
+
+    /// Reduces the elements to a single one, passing the index of each element to the operation, or returns [`None`]
+    /// if the iterator is empty.
+    ///
+    /// The index passed is that of the current element, so the first call receives `1`. This is Kotlin's
+    /// `reduceIndexed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let alternating = [10, 4, 3, 2]
+    ///     .into_iter()
+    ///     .reduce_indexed(|i, acc, n| if i % 2 == 0 { acc + n } else { acc - n });
+    ///
+    /// assert_eq!(alternating, Some(10 - 4 + 3 - 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn reduce_indexed<F>(self, mut op: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(usize, Self::Item, Self::Item) -> Self::Item,
+    {
+        let mut iter = self.enumerate();
+        let (_, first) = iter.next()?;
+        Some(iter.fold(first, |acc, (index, item)| op(index, acc, item)))
+    }
+
+    /// Maps each element together with its index, keeping only the [`Some`] results.
+    ///
+    /// This is Kotlin's `mapIndexedNotNull`, and avoids `enumerate().filter_map(|(i, t)| ...)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let columns = ["id", "", "name", "email"];
+    /// let headers: Vec<_> = columns
+    ///     .into_iter()
+    ///     .filter_map_indexed(|i, c| (!c.is_empty()).then(|| format!("{i}:{c}")))
+    ///     .collect();
+    ///
+    /// assert_eq!(headers, ["0:id", "2:name", "3:email"]);
+    /// ```
+    #[inline]
+    fn filter_map_indexed<R, F>(self, f: F) -> FilterMapIndexed<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize, Self::Item) -> Option<R>,
+    {
+        FilterMapIndexed { iter: self, index: 0, f }
+    }
+
+    /// Calls the specified function with a reference to each element as it passes through, returning the iterator for
+    /// further chaining.
+    ///
+    /// This is Kotlin's `onEach`, and is equivalent to [`Iterator::inspect`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let mut seen = Vec::new();
+    /// let even: Vec<_> = (1..=4).on_each(|&n| seen.push(n)).filter(|n| n % 2 == 0).collect();
+    ///
+    /// assert_eq!(seen, [1, 2, 3, 4]);
+    /// assert_eq!(even, [2, 4]);
+    /// ```
+    #[inline]
+    fn on_each<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item),
+    {
+        self.inspect(f)
+    }
+
+    /// Calls the specified function with the index of and a reference to each element as it passes through, returning
+    /// the iterator for further chaining.
+    ///
+    /// This is Kotlin's `onEachIndexed`, handy for reporting progress.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let mut progress = Vec::new();
+    /// let total: u32 = (1..=10)
+    ///     .on_each_indexed(|i, _| {
+    ///         if (i + 1) % 5 == 0 {
+    ///             progress.push(format!("{} items processed", i + 1));
+    ///         }
+    ///     })
+    ///     .sum();
+    ///
+    /// assert_eq!(total, 55);
+    /// assert_eq!(progress, ["5 items processed", "10 items processed"]);
+    /// ```
+    #[inline]
+    fn on_each_indexed<F>(self, f: F) -> OnEachIndexed<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize, &Self::Item),
+    {
+        OnEachIndexed { iter: self, index: 0, f }
+    }
+
+    /// Zips this iterator with two others, yielding flat `(a, b, c)` tuples until any of them is exhausted.
+    ///
+    /// Nested [`zip`](Iterator::zip) calls would yield `((a, b), c)` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let names = ["x", "y", "z"];
+    /// let mins = [0, -5, 2];
+    /// let maxs = [10, 5, 4];
+    ///
+    /// for (name, min, max) in names.into_iter().zip3(mins, maxs) {
+    ///     assert!(min < max, "invalid range for {name}");
+    /// }
+    /// ```
+    #[inline]
+    fn zip3<B, C>(self, b: B, c: C) -> Zip3<Self, B::IntoIter, C::IntoIter>
+    where
+        Self: Sized,
+        B: IntoIterator,
+        C: IntoIterator,
+    {
+        Zip3 { a: self, b: b.into_iter(), c: c.into_iter() }
+    }
+
+    /// Splits an iterator of 3-tuples into three collections.
+    ///
+    /// This is the three-element counterpart of [`Iterator::unzip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use kust::IteratorExt;
+    ///
+    /// let rows = [("Mike", 31, 'M'), ("Linda", 27, 'F'), ("Anna", 31, 'F')];
+    /// let (names, ages, _): (Vec<_>, HashSet<_>, String) = rows.into_iter().unzip3();
+    ///
+    /// assert_eq!(names, ["Mike", "Linda", "Anna"]);
+    /// assert_eq!(ages, HashSet::from([27, 31]));
+    /// ```
+    #[inline]
+    fn unzip3<A, B, C, FromA, FromB, FromC>(self) -> (FromA, FromB, FromC)
+    where
+        Self: Sized + Iterator<Item = (A, B, C)>,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+        FromC: Default + Extend<C>,
+    {
+        let (mut a, mut b, mut c) = (FromA::default(), FromB::default(), FromC::default());
+        for (x, y, z) in self {
+            a.extend(Some(x));
+            b.extend(Some(y));
+            c.extend(Some(z));
+        }
+        (a, b, c)
+    }
+
+    /// Returns an iterator over the values of the [`Some`] elements, dropping the [`None`]s.
+    ///
+    /// This is equivalent to `flatten()` or `filter_map(|x| x)`, but states the intent. This is Kotlin's
+    /// `filterNotNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let ports: Vec<u16> = ["80", "http", "443"].into_iter().map(|s| s.parse().ok()).flatten_options().collect();
+    ///
+    /// assert_eq!(ports, [80, 443]);
+    /// ```
+    #[inline]
+    fn flatten_options<T>(self) -> Flatten<Self>
+    where
+        Self: Sized + Iterator<Item = Option<T>>,
+    {
+        self.flatten()
+    }
+
+    /// Returns an iterator over the values of the [`Ok`] elements, silently dropping the errors.
+    ///
+    /// Use this only when discarding errors is intended - see [`ResultIterExt`](crate::ResultIterExt) for ways to
+    /// keep them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let numbers: Vec<i32> = "4,x,-2,".split(',').map(str::parse).flatten_results_ok().collect();
+    ///
+    /// assert_eq!(numbers, [4, -2]);
+    /// ```
+    #[inline]
+    fn flatten_results_ok<T, E>(self) -> Flatten<Self>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+    {
+        self.flatten()
+    }
+
+    /// Classifies and transforms each element in a single pass, collecting the [`Left`](Either::Left) values into
+    /// one [`Vec`] and the [`Right`](Either::Right) values into another.
+    ///
+    /// Unlike [`Iterator::partition`], the two collections can hold different types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::{Either, IteratorExt};
+    ///
+    /// let inputs = ["12", "abc", "7", "-"];
+    /// let (numbers, invalid): (Vec<i32>, _) = inputs.into_iter().partition_map(|s| match s.parse() {
+    ///     Ok(n) => Either::Left(n),
+    ///     Err(_) => Either::Right(s),
+    /// });
+    ///
+    /// assert_eq!(numbers, [12, 7]);
+    /// assert_eq!(invalid, ["abc", "-"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn partition_map<A, B, F>(self, mut f: F) -> (Vec<A>, Vec<B>)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Either<A, B>,
+    {
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        for item in self {
+            match f(item) {
+                Either::Left(a) => left.push(a),
+                Either::Right(b) => right.push(b),
+            }
+        }
+        (left, right)
+    }
+
+    /// Collects the key-value pairs returned by the specified function into a map, such as a [`HashMap`] or a
+    /// [`BTreeMap`](std::collections::BTreeMap).
+    ///
+    /// If several elements produce the same key, the last one wins. This is Kotlin's `associate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let users = [(7, "Mike"), (3, "Linda")];
+    /// let names: BTreeMap<_, _> = users.iter().associate(|&(id, name)| (name, id));
+    ///
+    /// assert_eq!(names, BTreeMap::from([("Linda", 3), ("Mike", 7)]));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn associate<K, V, M, F>(self, f: F) -> M
+    where
+        Self: Sized,
+        M: FromIterator<(K, V)>,
+        F: FnMut(Self::Item) -> (K, V),
+    {
+        self.map(f).collect()
+    }
+
+    /// Inserts the key-value pairs returned by the specified function into the given map.
+    ///
+    /// Existing entries with the same keys are overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let mut env = HashMap::from([("HOME", "/root".to_string())]);
+    /// ["PATH=/bin", "HOME=/home/mike"]
+    ///     .into_iter()
+    ///     .associate_to(&mut env, |line| {
+    ///         let (key, value) = line.split_once('=').unwrap();
+    ///         (key, value.to_string())
+    ///     });
+    ///
+    /// assert_eq!(env["HOME"], "/home/mike");
+    /// assert_eq!(env["PATH"], "/bin");
+    /// ```
+    #[inline]
+    fn associate_to<K, V, M, F>(self, map: &mut M, f: F)
+    where
+        Self: Sized,
+        M: Extend<(K, V)>,
+        F: FnMut(Self::Item) -> (K, V),
+    {
+        map.extend(self.map(f));
+    }
+
+    /// Collects an iterator of key-value pairs into a map, such as a [`HashMap`] or a
+    /// [`BTreeMap`](std::collections::BTreeMap).
+    ///
+    /// This names the intent of `collect::<HashMap<_, _>>()`. This is Kotlin's `toMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let scores: HashMap<_, _> = ["Mike", "Linda"].into_iter().zip([72, 95]).to_map();
+    ///
+    /// assert_eq!(scores["Linda"], 95);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_map<K, V, M>(self) -> M
+    where
+        Self: Sized + Iterator<Item = (K, V)>,
+        M: FromIterator<(K, V)>,
+    {
+        self.collect()
+    }
+
+    /// Inserts the key-value pairs into the given map, overwriting existing entries with the same keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let mut config = BTreeMap::from([("debug", false), ("verbose", false)]);
+    /// [("debug", true)].into_iter().to_map_to(&mut config);
+    ///
+    /// assert_eq!(config, BTreeMap::from([("debug", true), ("verbose", false)]));
+    /// ```
+    #[inline]
+    fn to_map_to<K, V, M>(self, map: &mut M)
+    where
+        Self: Sized + Iterator<Item = (K, V)>,
+        M: Extend<(K, V)>,
+    {
+        map.extend(self);
+    }
+
+    /// Returns an iterator over the maximal runs of consecutive elements with the same key returned by the specified
+    /// function, each yielded as the key and the elements of the run.
+    ///
+    /// Unlike [`group_by`](IteratorExt::group_by), which groups globally, equal keys that are not adjacent produce
+    /// separate runs. The iteration is streaming - only the current run is buffered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let encoded: String = "aaabccdd"
+    ///     .chars()
+    ///     .chunk_by(|&c| c)
+    ///     .map(|(c, run)| format!("{}{c}", run.len()))
+    ///     .collect();
+    ///
+    /// assert_eq!(encoded, "3a1b2c2d");
+    ///
+    /// let runs: Vec<_> = [1, 3, 2, 4, 5].into_iter().chunk_by(|n| n % 2).collect();
+    ///
+    /// assert_eq!(runs, [(1, vec![1, 3]), (0, vec![2, 4]), (1, vec![5])]);
+    /// ```
+    #[inline]
+    fn chunk_by<K, F>(self, key: F) -> ChunkBy<Self, K, F>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        ChunkBy { iter: self, key, pending: None }
+    }
+
+    /// Returns an iterator that yields a clone of `separator` between each pair of adjacent elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let path: String = IteratorExt::intersperse(["usr", "local", "bin"].into_iter(), "/").collect();
+    ///
+    /// assert_eq!(path, "usr/local/bin");
+    /// ```
+    ///
+    /// Note that [`Iterator`] has an unstable method with the same name, so calling this with method syntax causes an
+    /// `unstable_name_collisions` warning. Use the fully-qualified syntax shown above to avoid it.
+    #[inline]
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Intersperse { iter: self.peekable(), separator, needs_separator: false }
+    }
+
+    /// Returns an iterator that yields the value returned by the specified function between each pair of adjacent
+    /// elements.
+    ///
+    /// The function is only called when a separator is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let mut count = 0;
+    /// let separated: Vec<_> = IteratorExt::intersperse_with([1, 2, 3].into_iter(), || {
+    ///     count += 1;
+    ///     -count
+    /// })
+    /// .collect();
+    ///
+    /// assert_eq!(separated, [1, -1, 2, -2, 3]);
+    /// ```
+    ///
+    /// Note that [`Iterator`] has an unstable method with the same name, so calling this with method syntax causes an
+    /// `unstable_name_collisions` warning. Use the fully-qualified syntax shown above to avoid it.
+    #[inline]
+    fn intersperse_with<F>(self, separator: F) -> IntersperseWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> Self::Item,
+    {
+        IntersperseWith { iter: self.peekable(), separator, needs_separator: false }
+    }
+
+    /// Flattens an iterator of iterables, yielding a clone of `separator` between the elements of adjacent groups.
+    ///
+    /// A separator is yielded between every two groups, even if they are empty, just like [`slice::join`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let rows = [vec![1, 2], vec![3], vec![], vec![4]];
+    /// let flat: Vec<_> = rows.into_iter().joined(0).collect();
+    ///
+    /// assert_eq!(flat, [1, 2, 0, 3, 0, 0, 4]);
+    /// ```
+    #[inline]
+    fn joined<T>(self, separator: T) -> Joined<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator<Item = T>,
+        T: Clone,
+    {
+        Joined { iter: self, current: None, separator }
+    }
+
+    /// Returns an iterator over the prefix sums of the elements.
+    ///
+    /// This is [`running_reduce`](IteratorExt::running_reduce) with addition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let totals: Vec<_> = [3, 1, 4, 1, 5].into_iter().running_sum().collect();
+    ///
+    /// assert_eq!(totals, [3, 4, 8, 9, 14]);
+    /// ```
+    #[inline]
+    fn running_sum(self) -> RunningAggregate<Self>
+    where
+        Self: Sized,
+        Self::Item: Add<Output = Self::Item> + Clone,
+    {
+        self.running_reduce(Add::add)
+    }
+
+    /// Returns an iterator over the maximum of the elements so far, at each position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let peaks: Vec<_> = [3, 1, 4, 1, 5].into_iter().running_max().collect();
+    ///
+    /// assert_eq!(peaks, [3, 3, 4, 4, 5]);
+    /// ```
+    #[inline]
+    fn running_max(self) -> RunningAggregate<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.running_reduce(Ord::max)
+    }
+
+    /// Returns an iterator over the minimum of the elements so far, at each position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let lows: Vec<_> = "kotlin".chars().running_min().collect();
+    ///
+    /// assert_eq!(lows, ['k', 'k', 'k', 'k', 'i', 'i']);
+    /// ```
+    #[inline]
+    fn running_min(self) -> RunningAggregate<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.running_reduce(Ord::min)
+    }
+
+    /// Returns the largest of the values returned by the specified function for each element, or [`None`] if the
+    /// iterator is empty.
+    ///
+    /// Unlike [`Iterator::max_by_key`], the selected value is returned rather than the element. This is Kotlin's
+    /// `maxOfOrNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let lines = ["fn main() {", "    println!(\"hi\");", "}"];
+    ///
+    /// assert_eq!(lines.iter().max_of(|line| line.len()), Some(19));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn max_of<R, F>(self, f: F) -> Option<R>
+    where
+        Self: Sized,
+        R: Ord,
+        F: FnMut(Self::Item) -> R,
+    {
+        self.map(f).max()
+    }
+
+    /// Returns the smallest of the values returned by the specified function for each element, or [`None`] if the
+    /// iterator is empty.
+    ///
+    /// This is Kotlin's `minOfOrNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let words = ["kotlin", "rust", "go"];
+    ///
+    /// assert_eq!(words.into_iter().min_of(str::len), Some(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn min_of<R, F>(self, f: F) -> Option<R>
+    where
+        Self: Sized,
+        R: Ord,
+        F: FnMut(Self::Item) -> R,
+    {
+        self.map(f).min()
+    }
+
+    /// Returns the largest of the values returned by the specified function for each element, with respect to the
+    /// specified comparator, or [`None`] if the iterator is empty.
+    ///
+    /// This supports selected values that aren't [`Ord`], such as floats. This is Kotlin's `maxOfWithOrNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let points = [(1.0, 2.0), (3.0, 4.0), (0.5, 0.5)];
+    /// let farthest = points.iter().max_of_with(f64::total_cmp, |&(x, y): &(f64, f64)| x.hypot(y));
+    ///
+    /// assert_eq!(farthest, Some(5.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn max_of_with<R, C, F>(self, compare: C, f: F) -> Option<R>
+    where
+        Self: Sized,
+        C: FnMut(&R, &R) -> Ordering,
+        F: FnMut(Self::Item) -> R,
+    {
+        self.map(f).max_by(compare)
+    }
+
+    /// Returns the smallest of the values returned by the specified function for each element, with respect to the
+    /// specified comparator, or [`None`] if the iterator is empty.
+    ///
+    /// This is Kotlin's `minOfWithOrNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let prices = [("apple", 1.25), ("kiwi", 0.6), ("mango", 2.1)];
+    ///
+    /// assert_eq!(prices.iter().min_of_with(f64::total_cmp, |&(_, price)| price), Some(0.6));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn min_of_with<R, C, F>(self, compare: C, f: F) -> Option<R>
+    where
+        Self: Sized,
+        C: FnMut(&R, &R) -> Ordering,
+        F: FnMut(Self::Item) -> R,
+    {
+        self.map(f).min_by(compare)
+    }
+
+    /// Returns the first [`Some`] value returned by the specified function, or [`None`] if there is none.
+    ///
+    /// The function is applied lazily, and the iteration stops at the first [`Some`]. This is Kotlin's
+    /// `firstNotNullOfOrNull`, and is equivalent to [`Iterator::find_map`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let local = HashMap::from([("theme", "dark")]);
+    /// let global = HashMap::from([("theme", "light"), ("font", "mono")]);
+    /// let resolvers = [&local, &global];
+    ///
+    /// assert_eq!(resolvers.iter().first_not_none_of(|config| config.get("theme")), Some(&"dark"));
+    /// assert_eq!(resolvers.iter().first_not_none_of(|config| config.get("font")), Some(&"mono"));
+    /// assert_eq!(resolvers.iter().first_not_none_of(|config| config.get("size")), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn first_not_none_of<R, F>(&mut self, f: F) -> Option<R>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<R>,
+    {
+        self.find_map(f)
+    }
+
+    /// Returns an iterator over the segments of elements separated by the elements matching the specified predicate,
+    /// which are dropped.
+    ///
+    /// Like [`str::split`], adjacent or trailing delimiters produce empty segments, and an empty iterator produces a
+    /// single empty segment. Call [`skip_empty`](SplitBy::skip_empty) on the result to drop empty segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let lines = ["a = 1", "b = 2", "", "c = 3", "", ""];
+    ///
+    /// let sections: Vec<_> = lines.into_iter().split_by(|line| line.is_empty()).collect();
+    /// assert_eq!(sections, [vec!["a = 1", "b = 2"], vec!["c = 3"], vec![], vec![]]);
+    ///
+    /// let sections: Vec<_> = lines.into_iter().split_by(|line| line.is_empty()).skip_empty().collect();
+    /// assert_eq!(sections, [vec!["a = 1", "b = 2"], vec!["c = 3"]]);
+    /// ```
+    #[inline]
+    fn split_by<P>(self, predicate: P) -> SplitBy<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        SplitBy { iter: self, predicate, skip_empty: false, finished: false }
+    }
+
+    /// Appends the results of the specified function for each element to the given collection.
+    ///
+    /// This is Kotlin's `mapTo`, allowing buffers to be reused instead of collecting into a new one every time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let mut buffer = Vec::with_capacity(16);
+    /// for frame in [[1, 2], [3, 4]] {
+    ///     buffer.clear();
+    ///     frame.into_iter().map_to(&mut buffer, |sample| sample * 10);
+    ///     assert_eq!(buffer.len(), 2);
+    /// }
+    ///
+    /// assert_eq!(buffer, [30, 40]);
+    /// ```
+    #[inline]
+    fn map_to<R, C, F>(self, collection: &mut C, f: F)
+    where
+        Self: Sized,
+        C: Extend<R>,
+        F: FnMut(Self::Item) -> R,
+    {
+        collection.extend(self.map(f));
+    }
+
+    /// Appends all elements of the iterables returned by the specified function for each element to the given
+    /// collection.
+    ///
+    /// This is Kotlin's `flatMapTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let mut words = vec!["title"];
+    /// ["hello world", "goodbye"].into_iter().flat_map_to(&mut words, str::split_whitespace);
+    ///
+    /// assert_eq!(words, ["title", "hello", "world", "goodbye"]);
+    /// ```
+    #[inline]
+    fn flat_map_to<R, C, F>(self, collection: &mut C, f: F)
+    where
+        Self: Sized,
+        R: IntoIterator,
+        C: Extend<R::Item>,
+        F: FnMut(Self::Item) -> R,
+    {
+        collection.extend(self.flat_map(f));
+    }
+
+    /// Appends the elements matching the specified predicate to the given collection.
+    ///
+    /// This is Kotlin's `filterTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use kust::IteratorExt;
+    ///
+    /// let mut errors = BTreeSet::from([404]);
+    /// [200, 500, 404, 301].into_iter().filter_to(&mut errors, |&status| status >= 400);
+    ///
+    /// assert_eq!(errors, BTreeSet::from([404, 500]));
+    /// ```
+    #[inline]
+    fn filter_to<C, P>(self, collection: &mut C, predicate: P)
+    where
+        Self: Sized,
+        C: Extend<Self::Item>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        collection.extend(self.filter(predicate));
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// A primitive number that can be converted to [`f64`], for [`average_of`](IteratorExt::average_of).
+///
+/// Implemented for all integer and floating-point types with an `as` cast, so unlike with [`Into<f64>`], 64- and
+/// 128-bit integers are accepted too, rounded to the nearest representable value.
+pub trait ToF64 {
+    /// Converts this number to the nearest [`f64`].
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($ty:ty),*) => {$(
+        impl ToF64 for $ty {
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+}
+
+impl_to_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// An iterator over chunks of the elements of another iterator, created by [`chunked`](IteratorExt::chunked).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunked<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for Chunked<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<_> = self.iter.by_ref().take(self.size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.div_ceil(self.size), upper.map(|u| u.div_ceil(self.size)))
+    }
+}
+
+/// An iterator over the results of a function applied to chunks of another iterator, created by
+/// [`chunked_map`](IteratorExt::chunked_map).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkedMap<I: Iterator, F> {
+    iter: Peekable<I>,
+    size: usize,
+    f: F,
+}
+
+impl<I, R, F> Iterator for ChunkedMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(Chunk<'_, I>) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.peek()?;
+        Some((self.f)(Chunk { iter: &mut self.iter, remaining: self.size }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.div_ceil(self.size), upper.map(|u| u.div_ceil(self.size)))
+    }
+}
+
+/// A chunk of elements passed to the function of [`chunked_map`](IteratorExt::chunked_map).
+///
+/// Elements not consumed by the function are skipped when the chunk is dropped.
+pub struct Chunk<'a, I: Iterator> {
+    iter: &'a mut Peekable<I>,
+    remaining: usize,
+}
+
+impl<I: Iterator> Iterator for Chunk<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(self.remaining), Some(upper.map_or(self.remaining, |u| u.min(self.remaining))))
+    }
+}
+
+impl<I: Iterator> Drop for Chunk<'_, I> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// An iterator over sliding windows of the elements of another iterator, created by
+/// [`windowed`](IteratorExt::windowed).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windowed<I: Iterator> {
+    iter: Fuse<I>,
+    buffer: VecDeque<I::Item>,
+    size: usize,
+    step: usize,
+    partial: bool,
+    started: bool,
+}
+
+impl<I> Iterator for Windowed<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            if self.step <= self.buffer.len() {
+                self.buffer.drain(..self.step);
+            } else {
+                let skip = self.step - self.buffer.len();
+                self.buffer.clear();
+                self.iter.by_ref().take(skip).for_each(drop);
+            }
+        }
+        self.started = true;
+        let missing = self.size - self.buffer.len();
+        self.buffer.extend(self.iter.by_ref().take(missing));
+        if self.buffer.len() == self.size || (self.partial && !self.buffer.is_empty()) {
+            Some(self.buffer.iter().cloned().collect())
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over pairs of consecutive elements of another iterator, created by
+/// [`zip_with_next`](IteratorExt::zip_with_next).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipWithNext<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I> Iterator for ZipWithNext<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next()?;
+        self.previous = Some(next.clone());
+        Some((previous, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.previous.is_some() {
+            (lower, upper)
+        } else {
+            (lower.saturating_sub(1), upper.map(|u| u.saturating_sub(1)))
+        }
+    }
+}
+
+/// An iterator over the results of a function applied to pairs of consecutive elements of another iterator, created
+/// by [`zip_with_next_map`](IteratorExt::zip_with_next_map).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipWithNextMap<I: Iterator, F> {
+    iter: I,
+    previous: Option<I::Item>,
+    f: F,
+}
+
+impl<I, R, F> Iterator for ZipWithNextMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next()?;
+        let result = (self.f)(&previous, &next);
+        self.previous = Some(next);
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.previous.is_some() {
+            (lower, upper)
+        } else {
+            (lower.saturating_sub(1), upper.map(|u| u.saturating_sub(1)))
+        }
+    }
+}
+
+/// An iterator over the distinct elements of another iterator, created by [`distinct`](IteratorExt::distinct).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Distinct<I: Iterator> {
+    iter: I,
+    seen: HashSet<I::Item>,
+}
+
+impl<I> Iterator for Distinct<I>
+where
+    I: Iterator,
+    I::Item: Hash + Eq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let seen = &mut self.seen;
+        self.iter.find(|item| !seen.contains(item) && seen.insert(item.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator over the elements of another iterator with distinct keys, created by
+/// [`distinct_by`](IteratorExt::distinct_by).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DistinctBy<I, K, F> {
+    iter: I,
+    seen: HashSet<K>,
+    key: F,
+}
+
+impl<I, K, F> Iterator for DistinctBy<I, K, F>
+where
+    I: Iterator,
+    K: Hash + Eq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (seen, key) = (&mut self.seen, &mut self.key);
+        self.iter.find(|item| seen.insert(key(item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// A grouping of the elements of an iterator by key, created by [`grouping_by`](IteratorExt::grouping_by).
+///
+/// This is Kotlin's `Grouping`.
+#[must_use = "a grouping does nothing unless one of its operations is called"]
+pub struct Grouping<I, F> {
+    iter: I,
+    key: F,
+}
+
+impl<I, K, F> Grouping<I, F>
+where
+    I: Iterator,
+    K: Hash + Eq,
+    F: FnMut(&I::Item) -> K,
+{
+    /// Returns a map from each key to the number of elements with that key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let words = ["apple", "avocado", "banana"];
+    /// let counts = words.into_iter().grouping_by(|w| w.chars().next().unwrap()).each_count();
+    ///
+    /// assert_eq!(counts, HashMap::from([('a', 2), ('b', 1)]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn each_count(self) -> HashMap<K, usize> {
+        self.fold(0, |count, _| count + 1)
+    }
+
+    /// Accumulates the elements of each group, starting from a clone of `init` for each key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let orders = [("Mike", 20), ("Linda", 15), ("Mike", 5)];
+    /// let totals = orders.into_iter().grouping_by(|&(name, _)| name).fold(0, |total, (_, price)| total + price);
+    ///
+    /// assert_eq!(totals, HashMap::from([("Mike", 25), ("Linda", 15)]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fold<R, G>(self, init: R, mut op: G) -> HashMap<K, R>
+    where
+        R: Clone,
+        G: FnMut(R, I::Item) -> R,
+    {
+        self.aggregate(|_, acc, item| op(acc.unwrap_or_else(|| init.clone()), item))
+    }
+
+    /// Accumulates the elements of each group, starting from the first element of the group.
+    ///
+    /// The operation receives the key, the accumulated value and the current element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let longest = ["kiwi", "fig", "banana", "plum"]
+    ///     .into_iter()
+    ///     .grouping_by(|w| w.len() % 2)
+    ///     .reduce(|_, a, b| if b.len() > a.len() { b } else { a });
+    ///
+    /// assert_eq!(longest, HashMap::from([(0, "banana"), (1, "fig")]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reduce<G>(self, mut op: G) -> HashMap<K, I::Item>
+    where
+        G: FnMut(&K, I::Item, I::Item) -> I::Item,
+    {
+        self.aggregate(|key, acc, item| match acc {
+            Some(acc) => op(key, acc, item),
+            None => item,
+        })
+    }
+
+    /// Accumulates the elements of each group with the specified operation, which receives the key, the
+    /// accumulated value so far ([`None`] for the first element of the group) and the current element.
+    ///
+    /// This is the most general operation, which the others are built upon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::IteratorExt;
+    ///
+    /// let summary = [3, 8, 1, 6, 5].into_iter().grouping_by(|n| n % 2 == 0).aggregate(|even, acc, n| match acc {
+    ///     Some(acc) => format!("{acc}, {n}"),
+    ///     None => format!("{}: {n}", if *even { "even" } else { "odd" }),
+    /// });
+    ///
+    /// assert_eq!(summary[&true], "even: 8, 6");
+    /// assert_eq!(summary[&false], "odd: 3, 1, 5");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn aggregate<R, G>(self, mut op: G) -> HashMap<K, R>
+    where
+        G: FnMut(&K, Option<R>, I::Item) -> R,
+    {
+        let Self { iter, mut key } = self;
+        let mut map = HashMap::new();
+        for item in iter {
+            match map.entry(key(&item)) {
+                Entry::Occupied(entry) => {
+                    // `op` consumes the accumulator, so it has to be taken out of the map.
+                    let (key, acc) = entry.remove_entry();
+                    let value = op(&key, Some(acc), item);
+                    map.insert(key, value);
+                }
+                Entry::Vacant(entry) => {
+                    let value = op(entry.key(), None, item);
+                    entry.insert(value);
+                }
+            }
+        }
+        map
+    }
+}
+
+/// A builder for joining the elements of an iterator into a [`String`], created by
+/// [`join_to_string`](IteratorExt::join_to_string).
+#[must_use = "the string is only built when `build` is called"]
+pub struct JoinToString<'a, I: Iterator, F = fn(<I as Iterator>::Item) -> <I as Iterator>::Item> {
+    iter: I,
+    separator: &'a str,
+    prefix: &'a str,
+    postfix: &'a str,
+    limit: Option<usize>,
+    truncated: &'a str,
+    transform: F,
+}
+
+impl<'a, I: Iterator, F> JoinToString<'a, I, F> {
+    /// Sets the string placed before the joined elements. Defaults to an empty string.
+    #[inline]
+    pub fn prefix(self, prefix: &'a str) -> Self {
+        Self { prefix, ..self }
+    }
+
+    /// Sets the string placed after the joined elements. Defaults to an empty string.
+    #[inline]
+    pub fn postfix(self, postfix: &'a str) -> Self {
+        Self { postfix, ..self }
+    }
+
+    /// Sets the maximum number of elements to join. If there are more, the
+    /// [`truncated`](JoinToString::truncated) string is appended after the last joined element.
+    #[inline]
+    pub fn limit(self, limit: usize) -> Self {
+        Self { limit: Some(limit), ..self }
+    }
+
+    /// Sets the string appended in place of the elements left out by the [`limit`](JoinToString::limit).
+    /// Defaults to `"..."`.
+    #[inline]
+    pub fn truncated(self, truncated: &'a str) -> Self {
+        Self { truncated, ..self }
+    }
+
+    /// Sets a function that converts each element into the value that is displayed.
+    #[inline]
+    pub fn transform<G, D>(self, transform: G) -> JoinToString<'a, I, G>
+    where
+        G: FnMut(I::Item) -> D,
+        D: Display,
+    {
+        let Self { iter, separator, prefix, postfix, limit, truncated, .. } = self;
+        JoinToString { iter, separator, prefix, postfix, limit, truncated, transform }
+    }
+
+    /// Joins the elements into a [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::IteratorExt;
+    ///
+    /// let words = ["alpha", "beta", "gamma"];
+    ///
+    /// assert_eq!(words.iter().join_to_string("").limit(0).truncated("<more>").build(), "<more>");
+    /// assert_eq!(words.iter().join_to_string("-").limit(5).build(), "alpha-beta-gamma");
+    /// ```
+    #[must_use]
+    pub fn build<D>(self) -> String
+    where
+        F: FnMut(I::Item) -> D,
+        D: Display,
+    {
+        let Self { iter, separator, prefix, postfix, limit, truncated, mut transform } = self;
+        let mut result = String::from(prefix);
+        for (index, item) in iter.enumerate() {
+            if index > 0 {
+                result.push_str(separator);
+            }
+            if limit.is_some_and(|limit| index >= limit) {
+                result.push_str(truncated);
+                break;
+            }
+            let _ = write!(result, "{}", transform(item));
+        }
+        result.push_str(postfix);
+        result
+    }
+}
+
+/// The error returned by [`single`](IteratorExt::single) and [`single_by`](IteratorExt::single_by) when there
+/// isn't exactly one element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SingleError {
+    /// There are no (matching) elements.
+    Empty,
+    /// There is more than one (matching) element.
+    MoreThanOne,
+}
+
+impl Display for SingleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SingleError::Empty => f.write_str("Iterator has no elements."),
+            SingleError::MoreThanOne => f.write_str("Iterator has more than one element."),
+        }
+    }
+}
+
+impl Error for SingleError {}
+
+/// An iterator over the last elements of another iterator, created by [`take_last`](IteratorExt::take_last).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeLast<I: Iterator> {
+    iter: I,
+    n: usize,
+    buffer: Option<VecDeque<I::Item>>,
+}
+
+impl<I: Iterator> Iterator for TakeLast<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buffer = self.buffer.get_or_insert_with(|| {
+            // Grown as needed rather than allocated for `n` elements up front, as `n` can be much larger.
+            let mut buffer = VecDeque::new();
+            for item in &mut self.iter {
+                if buffer.len() == self.n {
+                    if self.n == 0 {
+                        continue;
+                    }
+                    buffer.pop_front();
+                }
+                buffer.push_back(item);
+            }
+            buffer
+        });
+        buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.buffer {
+            Some(buffer) => (buffer.len(), Some(buffer.len())),
+            None => {
+                let (lower, upper) = self.iter.size_hint();
+                (lower.min(self.n), Some(upper.map_or(self.n, |u| u.min(self.n))))
+            }
+        }
+    }
+}
+
+/// An iterator over all but the last elements of another iterator, created by
+/// [`drop_last`](IteratorExt::drop_last).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DropLast<I: Iterator> {
+    iter: I,
+    n: usize,
+    buffer: VecDeque<I::Item>,
+}
+
+impl<I: Iterator> Iterator for DropLast<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.n {
+            self.buffer.push_back(self.iter.next()?);
+        }
+        let item = self.iter.next()?;
+        self.buffer.push_back(item);
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let buffered = self.buffer.len();
+        (
+            lower.saturating_add(buffered).saturating_sub(self.n),
+            upper.and_then(|u| u.checked_add(buffered)).map(|u| u.saturating_sub(self.n)),
+        )
+    }
+}
+
+/// An iterator over the trailing elements of another iterator that match a predicate, created by
+/// [`take_last_while`](IteratorExt::take_last_while).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeLastWhile<I: Iterator, P> {
+    iter: I,
+    predicate: P,
+    buffer: Option<vec::IntoIter<I::Item>>,
+}
+
+impl<I, P> Iterator for TakeLastWhile<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (iter, predicate) = (&mut self.iter, &mut self.predicate);
+        self.buffer
+            .get_or_insert_with(|| {
+                let mut run = Vec::new();
+                for item in iter {
+                    if predicate(&item) {
+                        run.push(item);
+                    } else {
+                        run.clear();
+                    }
+                }
+                run.into_iter()
+            })
+            .next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.buffer {
+            Some(buffer) => buffer.size_hint(),
+            None => (0, self.iter.size_hint().1),
+        }
+    }
+}
+
+/// An iterator over the elements of another iterator except the trailing ones that match a predicate, created by
+/// [`drop_last_while`](IteratorExt::drop_last_while).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DropLastWhile<I: Iterator, P> {
+    iter: I,
+    predicate: P,
+    pending: VecDeque<I::Item>,
+    ready: usize,
+}
+
+impl<I, P> Iterator for DropLastWhile<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ready > 0 {
+            self.ready -= 1;
+            return self.pending.pop_front();
+        }
+        loop {
+            let item = self.iter.next()?;
+            if (self.predicate)(&item) {
+                self.pending.push_back(item);
+            } else if self.pending.is_empty() {
+                return Some(item);
+            } else {
+                self.pending.push_back(item);
+                self.ready = self.pending.len() - 1;
+                return self.pending.pop_front();
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.iter.size_hint().1;
+        (self.ready, upper.and_then(|u| u.checked_add(self.pending.len())))
+    }
+}
+
+/// An iterator over the accumulated values of a fold, created by [`running_fold`](IteratorExt::running_fold).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningFold<I, R, F> {
+    iter: I,
+    acc: Option<R>,
+    op: F,
+    started: bool,
+}
+
+impl<I, R, F> Iterator for RunningFold<I, R, F>
+where
+    I: Iterator,
+    R: Clone,
+    F: FnMut(R, I::Item) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return self.acc.clone();
+        }
+        let item = self.iter.next()?;
+        let acc = (self.op)(self.acc.take()?, item);
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.started {
+            (lower, upper)
+        } else {
+            (lower.saturating_add(1), upper.and_then(|u| u.checked_add(1)))
+        }
+    }
+}
+
+/// An iterator over the accumulated values of a reduction, created by
+/// [`running_reduce`](IteratorExt::running_reduce).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningReduce<I: Iterator, F> {
+    iter: I,
+    acc: Option<I::Item>,
+    op: F,
+}
+
+impl<I, F> Iterator for RunningReduce<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(I::Item, I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let acc = match self.acc.take() {
+            Some(acc) => (self.op)(acc, item),
+            None => item,
+        };
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator over a cumulative aggregate of the elements of another iterator, created by
+/// [`running_sum`](IteratorExt::running_sum), [`running_max`](IteratorExt::running_max) and
+/// [`running_min`](IteratorExt::running_min).
+pub type RunningAggregate<I> =
+    RunningReduce<I, fn(<I as Iterator>::Item, <I as Iterator>::Item) -> <I as Iterator>::Item>;
+
+/// An iterator that maps and filters the elements of another iterator along with their index, created by
+/// [`filter_map_indexed`](IteratorExt::filter_map_indexed).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterMapIndexed<I, F> {
+    iter: I,
+    index: usize,
+    f: F,
+}
+
+impl<I, R, F> Iterator for FilterMapIndexed<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize, I::Item) -> Option<R>,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in &mut self.iter {
+            let index = self.index;
+            self.index += 1;
+            if let Some(result) = (self.f)(index, item) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator that calls a function with the index of and a reference to each element of another iterator, created
+/// by [`on_each_indexed`](IteratorExt::on_each_indexed).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OnEachIndexed<I, F> {
+    iter: I,
+    index: usize,
+    f: F,
+}
+
+impl<I, F> Iterator for OnEachIndexed<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize, &I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        (self.f)(self.index, &item);
+        self.index += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator over three iterators simultaneously, created by [`zip3`](IteratorExt::zip3).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Zip3<A, B, C> {
+    a: A,
+    b: B,
+    c: C,
+}
+
+impl<A, B, C> Iterator for Zip3<A, B, C>
+where
+    A: Iterator,
+    B: Iterator,
+    C: Iterator,
+{
+    type Item = (A::Item, B::Item, C::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.a.next()?, self.b.next()?, self.c.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hints = [self.a.size_hint(), self.b.size_hint(), self.c.size_hint()];
+        let lower = hints.iter().map(|&(lower, _)| lower).min().unwrap_or(0);
+        let upper = hints.iter().filter_map(|&(_, upper)| upper).min();
+        (lower, upper)
+    }
+}
+
+/// An iterator over runs of consecutive elements of another iterator with the same key, created by
+/// [`chunk_by`](IteratorExt::chunk_by).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkBy<I: Iterator, K, F> {
+    iter: I,
+    key: F,
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I, K, F> Iterator for ChunkBy<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (run_key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next()?;
+                ((self.key)(&item), item)
+            }
+        };
+        let mut run = vec![first];
+        for item in &mut self.iter {
+            let key = (self.key)(&item);
+            if key == run_key {
+                run.push(item);
+            } else {
+                self.pending = Some((key, item));
+                break;
+            }
+        }
+        Some((run_key, run))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let pending = usize::from(self.pending.is_some());
+        (usize::from(lower > 0 || pending > 0), upper.and_then(|u| u.checked_add(pending)))
+    }
+}
+
+/// An iterator that places a separator between the elements of another iterator, created by
+/// [`intersperse`](IteratorExt::intersperse).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    separator: I::Item,
+    needs_separator: bool,
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_separator && self.iter.peek().is_some() {
+            self.needs_separator = false;
+            return Some(self.separator.clone());
+        }
+        let item = self.iter.next()?;
+        self.needs_separator = true;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.needs_separator)
+    }
+}
+
+/// An iterator that places separators returned by a function between the elements of another iterator, created by
+/// [`intersperse_with`](IteratorExt::intersperse_with).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseWith<I: Iterator, F> {
+    iter: Peekable<I>,
+    separator: F,
+    needs_separator: bool,
+}
+
+impl<I, F> Iterator for IntersperseWith<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_separator && self.iter.peek().is_some() {
+            self.needs_separator = false;
+            return Some((self.separator)());
+        }
+        let item = self.iter.next()?;
+        self.needs_separator = true;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.needs_separator)
+    }
+}
+
+/// Returns the size hint of an intersperse adapter over `iter`, which has `n` elements left, yielding `n - 1`
+/// separators, plus one more if an element was already yielded.
+fn intersperse_size_hint<I: Iterator>(iter: &Peekable<I>, needs_separator: bool) -> (usize, Option<usize>) {
+    let (lower, upper) = iter.size_hint();
+    let with_separators = |n: usize| match n {
+        0 => Some(0),
+        n => n.checked_mul(2)?.checked_sub(usize::from(!needs_separator)),
+    };
+    (with_separators(lower).unwrap_or(usize::MAX), upper.and_then(with_separators))
+}
+
+/// An iterator that flattens groups of elements, placing a separator between them, created by
+/// [`joined`](IteratorExt::joined).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Joined<I>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+{
+    iter: I,
+    current: Option<<I::Item as IntoIterator>::IntoIter>,
+    separator: <I::Item as IntoIterator>::Item,
+}
+
+impl<I> Iterator for Joined<I>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Clone,
+{
+    type Item = <I::Item as IntoIterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.current.as_mut().and_then(Iterator::next) {
+                return Some(item);
+            }
+            let group = self.iter.next()?;
+            if self.current.replace(group.into_iter()).is_some() {
+                return Some(self.separator.clone());
+            }
+        }
+    }
+}
+
+/// An iterator over the segments of another iterator separated by delimiter elements, created by
+/// [`split_by`](IteratorExt::split_by).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitBy<I, P> {
+    iter: I,
+    predicate: P,
+    skip_empty: bool,
+    finished: bool,
+}
+
+impl<I, P> SplitBy<I, P> {
+    /// Makes the iterator skip empty segments, such as those between adjacent delimiters.
+    #[inline]
+    pub fn skip_empty(self) -> Self {
+        Self { skip_empty: true, ..self }
+    }
+}
+
+impl<I, P> Iterator for SplitBy<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let mut segment = Vec::new();
+            loop {
+                match self.iter.next() {
+                    Some(item) if (self.predicate)(&item) => break,
+                    Some(item) => segment.push(item),
+                    None => {
+                        self.finished = true;
+                        break;
+                    }
+                }
+            }
+            if !(self.skip_empty && segment.is_empty()) {
+                return Some(segment);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        let upper = self.iter.size_hint().1;
+        (usize::from(!self.skip_empty), upper.and_then(|u| u.checked_add(1)))
+    }
+}
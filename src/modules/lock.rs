@@ -0,0 +1,196 @@
+use std::sync::{LockResult, Mutex, RwLock};
+
+/// Determines how a poisoned lock is handled by the lock scope functions, such as
+/// [`with_lock_policy`](MutexExt::with_lock_policy).
+///
+/// A lock is poisoned when a thread panics while holding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PoisonPolicy {
+    /// Panic if the lock is poisoned, like calling `unwrap` on the lock result.
+    #[default]
+    Panic,
+    /// Ignore the poisoning and access the protected data anyway.
+    Recover,
+}
+
+impl PoisonPolicy {
+    #[inline]
+    fn handle<G>(self, result: LockResult<G>) -> G {
+        match self {
+            PoisonPolicy::Panic => result.expect("lock poisoned"),
+            PoisonPolicy::Recover => result.unwrap_or_else(|e| e.into_inner()),
+        }
+    }
+}
+
+pub trait MutexExt<T: ?Sized> {
+    /// Acquires the lock, calls the specified function with a mutable reference to the protected data, releases
+    /// the lock and returns the result of the function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned. See [`with_lock_policy`](MutexExt::with_lock_policy) to handle poisoning
+    /// differently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    /// use kust::MutexExt;
+    ///
+    /// let counter = Mutex::new(0);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| counter.with_lock(|c| *c += 1));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(counter.with_lock(|c| *c), 4);
+    /// ```
+    #[inline]
+    fn with_lock<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.with_lock_policy(PoisonPolicy::Panic, f)
+    }
+
+    /// Acquires the lock, handling poisoning according to the given policy, calls the specified function with a
+    /// mutable reference to the protected data, releases the lock and returns the result of the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    /// use kust::{MutexExt, PoisonPolicy};
+    ///
+    /// let names = Mutex::new(vec!["Mike"]);
+    ///
+    /// // Poison the lock by panicking while holding it
+    /// let _ = std::thread::scope(|s| s.spawn(|| names.with_lock(|_| panic!("oops"))).join());
+    /// assert!(names.is_poisoned());
+    ///
+    /// names.with_lock_policy(PoisonPolicy::Recover, |n| n.push("Linda"));
+    /// assert_eq!(names.with_lock_policy(PoisonPolicy::Recover, |n| n.len()), 2);
+    /// ```
+    fn with_lock_policy<F, R>(&self, policy: PoisonPolicy, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T: ?Sized> MutexExt<T> for Mutex<T> {
+    #[inline]
+    fn with_lock_policy<F, R>(&self, policy: PoisonPolicy, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut *policy.handle(self.lock()))
+    }
+}
+
+pub trait RwLockExt<T: ?Sized> {
+    /// Acquires shared read access, calls the specified function with an immutable reference to the protected data,
+    /// releases the lock and returns the result of the function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned. See [`with_read_policy`](RwLockExt::with_read_policy) to handle poisoning
+    /// differently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::RwLock;
+    /// use kust::RwLockExt;
+    ///
+    /// let config = RwLock::new(String::from("debug"));
+    ///
+    /// assert!(config.with_read(|c| c == "debug"));
+    /// ```
+    #[inline]
+    fn with_read<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.with_read_policy(PoisonPolicy::Panic, f)
+    }
+
+    /// Acquires exclusive write access, calls the specified function with a mutable reference to the protected data,
+    /// releases the lock and returns the result of the function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned. See [`with_write_policy`](RwLockExt::with_write_policy) to handle poisoning
+    /// differently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::RwLock;
+    /// use kust::RwLockExt;
+    ///
+    /// let config = RwLock::new(String::from("debug"));
+    /// config.with_write(|c| c.push_str(",trace"));
+    ///
+    /// assert_eq!(config.with_read(|c| c.clone()), "debug,trace");
+    /// ```
+    #[inline]
+    fn with_write<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.with_write_policy(PoisonPolicy::Panic, f)
+    }
+
+    /// Like [`with_read`](RwLockExt::with_read), but handles poisoning according to the given policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::RwLock;
+    /// use kust::{PoisonPolicy, RwLockExt};
+    ///
+    /// let level = RwLock::new(3);
+    ///
+    /// assert_eq!(level.with_read_policy(PoisonPolicy::Recover, |l| *l), 3);
+    /// ```
+    fn with_read_policy<F, R>(&self, policy: PoisonPolicy, f: F) -> R
+    where
+        F: FnOnce(&T) -> R;
+
+    /// Like [`with_write`](RwLockExt::with_write), but handles poisoning according to the given policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::RwLock;
+    /// use kust::{PoisonPolicy, RwLockExt};
+    ///
+    /// let level = RwLock::new(3);
+    /// level.with_write_policy(PoisonPolicy::Recover, |l| *l += 1);
+    ///
+    /// assert_eq!(level.with_read(|l| *l), 4);
+    /// ```
+    fn with_write_policy<F, R>(&self, policy: PoisonPolicy, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T: ?Sized> RwLockExt<T> for RwLock<T> {
+    #[inline]
+    fn with_read_policy<F, R>(&self, policy: PoisonPolicy, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        f(&*policy.handle(self.read()))
+    }
+
+    #[inline]
+    fn with_write_policy<F, R>(&self, policy: PoisonPolicy, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut *policy.handle(self.write()))
+    }
+}
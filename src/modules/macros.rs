@@ -0,0 +1,251 @@
+/// Chains function calls left-to-right, passing the value as the first argument of each function.
+///
+/// Each stage is either a function path, optionally followed by the remaining arguments in parentheses, or a
+/// parenthesized expression evaluating to a function, such as a closure. `pipe!(x => f => g(a, b) => h)` expands to
+/// `h(g(f(x), a, b))`.
+///
+/// Together with [`using`](crate::ScopeFunctions::using), this allows expressing whole data pipelines left-to-right,
+/// even through free functions that aren't methods.
+///
+/// # Examples
+///
+/// ```
+/// use kust::pipe;
+///
+/// fn double(x: i32) -> i32 {
+///     x * 2
+/// }
+///
+/// fn add(x: i32, y: i32) -> i32 {
+///     x + y
+/// }
+///
+/// let result = pipe!(5 => double => add(3) => (|x| x - 1) => i32::pow(2));
+///
+/// assert_eq!(result, 144);
+/// ```
+#[macro_export]
+macro_rules! pipe {
+    (@acc [$acc:expr]) => {
+        $acc
+    };
+    (@acc [$acc:expr] => ($f:expr) $($rest:tt)*) => {
+        $crate::pipe!(@acc [($f)($acc)] $($rest)*)
+    };
+    (@acc [$acc:expr] => $head:ident $($rest:tt)*) => {
+        $crate::pipe!(@path [$acc] [$head] $($rest)*)
+    };
+    (@path [$acc:expr] [$($path:tt)*] :: $seg:ident $($rest:tt)*) => {
+        $crate::pipe!(@path [$acc] [$($path)* :: $seg] $($rest)*)
+    };
+    (@path [$acc:expr] [$($path:tt)*] ($($args:tt)*) $($rest:tt)*) => {
+        $crate::pipe!(@acc [$($path)*($acc, $($args)*)] $($rest)*)
+    };
+    (@path [$acc:expr] [$($path:tt)*] $($rest:tt)*) => {
+        $crate::pipe!(@acc [$($path)*($acc)] $($rest)*)
+    };
+    ($value:expr $(=> $($rest:tt)*)?) => {
+        $crate::pipe!(@acc [$value] $(=> $($rest)*)?)
+    };
+}
+
+/// Evaluates the body only if all the given [`Option`]s are `Some`, returning an `Option` of the body's result.
+///
+/// This mirrors Kotlin's safe-call-plus-`let` chains (`a?.let { ... }`), without deeply nested `if let`s. The options
+/// are evaluated left-to-right, stopping at the first `None`. Each closure parameter can be any irrefutable pattern.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use kust::let_some;
+///
+/// let ages = HashMap::from([("Mike", 34), ("Linda", 25)]);
+///
+/// let total = let_some!(ages.get("Mike"), ages.get("Linda") => |a, b| a + b);
+/// assert_eq!(total, Some(59));
+///
+/// let missing = let_some!(ages.get("Mike"), ages.get("Nobody") => |a, b| a + b);
+/// assert_eq!(missing, None);
+///
+/// let greeting = let_some!(ages.get_key_value("Mike") => |(name, age)| format!("{name} is {age}"));
+/// assert_eq!(greeting.as_deref(), Some("Mike is 34"));
+/// ```
+#[macro_export]
+macro_rules! let_some {
+    ($option:expr => |$param:pat_param| $body:expr) => {
+        match $option {
+            ::core::option::Option::Some($param) => ::core::option::Option::Some($body),
+            ::core::option::Option::None => ::core::option::Option::None,
+        }
+    };
+    ($option:expr, $($rest:expr),+ => |$param:pat_param, $($params:pat_param),+| $body:expr) => {
+        match $option {
+            ::core::option::Option::Some($param) => $crate::let_some!($($rest),+ => |$($params),+| $body),
+            ::core::option::Option::None => ::core::option::Option::None,
+        }
+    };
+}
+
+/// Unwraps an [`Option`] or [`Result`], evaluating the fallback expression if it is `None` or `Err`.
+///
+/// This replicates Kotlin's elvis operator (`?:`). As the fallback is evaluated in place, it can contain control flow,
+/// such as `return`, `break` or `continue`.
+///
+/// # Examples
+///
+/// ```
+/// use kust::elvis;
+///
+/// fn port(input: Option<&str>) -> Result<u16, String> {
+///     let input = elvis!(input, return Err(String::from("no port given")));
+///     let port = elvis!(input.parse::<u16>(), 8080);
+///     Ok(port)
+/// }
+///
+/// assert_eq!(port(Some("3000")), Ok(3000));
+/// assert_eq!(port(Some("not a port")), Ok(8080));
+/// assert_eq!(port(None), Err(String::from("no port given")));
+///
+/// let mut sum = 0;
+/// for entry in ["1", "x", "3"] {
+///     sum += elvis!(entry.parse::<i32>(), continue);
+/// }
+/// assert_eq!(sum, 4);
+/// ```
+#[macro_export]
+macro_rules! elvis {
+    ($value:expr, $fallback:expr $(,)?) => {
+        match $crate::__private::IntoOption::into_option($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => $fallback,
+        }
+    };
+}
+
+/// Expands a null-safe call chain on [`Option`] receivers, like Kotlin's `a?.b()?.c`.
+///
+/// Each `?` marks the preceding expression as an `Option`. If it is `None`, the whole chain short-circuits to `None`;
+/// otherwise, the chain continues with the contained value. The segments between `?`s can contain any field accesses,
+/// method calls and indexing. `safe!(a?.b()?.c.d())` expands to `a.and_then(|v| v.b()).map(|v| v.c.d())`.
+///
+/// The result is always an `Option`: if the chain doesn't end with `?`, the last segment is wrapped in `Some`.
+///
+/// # Examples
+///
+/// ```
+/// use kust::safe;
+///
+/// struct Address {
+///     city: String,
+/// }
+///
+/// struct User {
+///     address: Option<Address>,
+/// }
+///
+/// impl User {
+///     fn address(&self) -> Option<&Address> {
+///         self.address.as_ref()
+///     }
+/// }
+///
+/// let users = [
+///     User { address: Some(Address { city: String::from("Paris") }) },
+///     User { address: None },
+/// ];
+///
+/// assert_eq!(safe!(users.first()?.address()?.city.len()), Some(5));
+/// assert_eq!(safe!(users.get(1)?.address()?.city.len()), None);
+/// assert_eq!(safe!(users.get(7)?.address()?.city.len()), None);
+/// ```
+#[macro_export]
+macro_rules! safe {
+    (@head [$($head:tt)*] ? $($rest:tt)*) => {
+        $crate::safe!(@chain [($($head)*)] [] $($rest)*)
+    };
+    (@head [$($head:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::safe!(@head [$($head)* $next] $($rest)*)
+    };
+    (@head [$($head:tt)*]) => {
+        ::core::option::Option::Some($($head)*)
+    };
+    (@chain [$acc:expr] [$($segment:tt)*] ? $($rest:tt)*) => {
+        $crate::safe!(@chain [$acc.and_then(|value| value $($segment)*)] [] $($rest)*)
+    };
+    (@chain [$acc:expr] [$($segment:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::safe!(@chain [$acc] [$($segment)* $next] $($rest)*)
+    };
+    (@chain [$acc:expr] []) => {
+        $acc
+    };
+    (@chain [$acc:expr] [$($segment:tt)+]) => {
+        $acc.map(|value| value $($segment)+)
+    };
+    ($($tokens:tt)+) => {
+        $crate::safe!(@head [] $($tokens)+)
+    };
+}
+
+/// Evaluates a block in which `?` can be used, returning a [`Result`] instead of propagating errors to the enclosing
+/// function.
+///
+/// The value of the block is wrapped in `Ok`, while any error propagated with `?` is returned as `Err`, similar to
+/// unstable `try` blocks. The block is evaluated inside an immediately-invoked closure, so `return` returns from the
+/// block and `break` / `continue` can't target enclosing loops.
+///
+/// The error type can be given explicitly with `try_run!(-> Result<T, E> { ... })`, in which case the block must
+/// evaluate to a `Result` itself. Otherwise, it is inferred from the context, which typically requires a type
+/// annotation.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::ParseIntError;
+/// use kust::try_run;
+///
+/// let input = "3,4";
+///
+/// let sum: Result<i32, ParseIntError> = try_run! {
+///     let (a, b) = input.split_once(',').unwrap_or((input, "0"));
+///     a.parse::<i32>()? + b.parse::<i32>()?
+/// };
+/// assert_eq!(sum, Ok(7));
+///
+/// let product = try_run!(-> Result<i32, Box<dyn std::error::Error>> {
+///     let (a, b) = "3,x".split_once(',').ok_or("missing comma")?;
+///     Ok(a.parse::<i32>()? * b.parse::<i32>()?)
+/// });
+/// assert_eq!(product.unwrap_err().to_string(), "invalid digit found in string");
+/// ```
+#[macro_export]
+macro_rules! try_run {
+    (-> $ret:ty { $($body:tt)* }) => {
+        (|| -> $ret { $($body)* })()
+    };
+    ($($body:tt)*) => {
+        (|| ::core::result::Result::Ok({ $($body)* }))()
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    /// Conversion into an [`Option`], discarding the error of a [`Result`]. Used by [`elvis!`](crate::elvis).
+    pub trait IntoOption<T> {
+        fn into_option(self) -> Option<T>;
+    }
+
+    impl<T> IntoOption<T> for Option<T> {
+        #[inline]
+        fn into_option(self) -> Option<T> {
+            self
+        }
+    }
+
+    impl<T, E> IntoOption<T> for Result<T, E> {
+        #[inline]
+        fn into_option(self) -> Option<T> {
+            self.ok()
+        }
+    }
+}
@@ -0,0 +1,897 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash};
+use std::ops::{Deref, DerefMut};
+
+/// Kotlin-style extensions for maps, implemented for [`HashMap`] and [`BTreeMap`].
+///
+/// Functions looking up a key accept any borrowed form `Q` of it, like [`HashMap::get`], through the [`MapLookup`]
+/// bound, which abstracts over the bounds required by each map.
+pub trait MapExt<K, V>: Sized {
+    /// The type of this map with other key and value types, such as `HashMap<K2, V2, S>` for a `HashMap<K, V, S>`.
+    type Map<K2, V2>;
+
+    /// Returns the value for the given key, first inserting the value returned by the specified function if the key is
+    /// not present.
+    ///
+    /// The key is passed by reference and only cloned into an owned key when it is not present, unlike with
+    /// `map.entry(key.to_owned()).or_insert_with(f)`. This is Kotlin's `getOrPut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+    /// for (line, text) in ["use kust", "fn main", "use std"].into_iter().enumerate() {
+    ///     let word = text.split(' ').next().unwrap();
+    ///     index.get_or_put(word, Vec::new).push(line);
+    /// }
+    ///
+    /// assert_eq!(index["use"], [0, 2]);
+    /// assert_eq!(index["fn"], [1]);
+    /// ```
+    fn get_or_put<Q, F>(&mut self, key: &Q, f: F) -> &mut V
+    where
+        Self: MapLookup<Q, V>,
+        Q: ToOwned<Owned = K> + ?Sized,
+        F: FnOnce() -> V;
+
+    /// Returns a clone of the value for the given key, or the value returned by the specified function if the key is
+    /// not present.
+    ///
+    /// This is Kotlin's `getOrElse`, in place of `map.get(key).cloned().unwrap_or_else(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let env = HashMap::from([("HOME".to_string(), "/home/mike".to_string())]);
+    ///
+    /// assert_eq!(env.get_or_else("HOME", || "/".to_string()), "/home/mike");
+    /// assert_eq!(env.get_or_else("SHELL", || "/bin/sh".to_string()), "/bin/sh");
+    /// ```
+    #[must_use]
+    fn get_or_else<Q, F>(&self, key: &Q, f: F) -> V
+    where
+        Self: MapLookup<Q, V>,
+        Q: ?Sized,
+        V: Clone,
+        F: FnOnce() -> V;
+
+    /// Returns a clone of the value for the given key, or the default value if the key is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::MapExt;
+    ///
+    /// let stock = BTreeMap::from([("apple", 12), ("kiwi", 0)]);
+    ///
+    /// assert_eq!(stock.get_or_default("apple"), 12);
+    /// assert_eq!(stock.get_or_default("mango"), 0);
+    /// ```
+    #[must_use]
+    fn get_or_default<Q>(&self, key: &Q) -> V
+    where
+        Self: MapLookup<Q, V>,
+        Q: ?Sized,
+        V: Default + Clone;
+
+    /// Returns a map with the same keys, and values transformed by the specified function.
+    ///
+    /// This is Kotlin's `mapValues`. The map is consumed, so that neither keys nor values need to be cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let prices = HashMap::from([("apple", 120), ("kiwi", 45)]);
+    /// let formatted = prices.map_values(|cents| format!("${}.{:02}", cents / 100, cents % 100));
+    ///
+    /// assert_eq!(formatted["apple"], "$1.20");
+    /// assert_eq!(formatted["kiwi"], "$0.45");
+    /// ```
+    #[must_use]
+    fn map_values<V2, F>(self, f: F) -> Self::Map<K, V2>
+    where
+        Self::Map<K, V2>: FromIterator<(K, V2)>,
+        F: FnMut(V) -> V2;
+
+    /// Returns a map with the same values, and keys transformed by the specified function.
+    ///
+    /// If several keys are transformed into the same key, only the last of their values in iteration order is kept.
+    /// For a [`HashMap`], this order and thus the kept value are unspecified; see
+    /// [`try_map_keys`](MapExt::try_map_keys) to detect collisions instead. This is Kotlin's `mapKeys`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::MapExt;
+    ///
+    /// let headers = BTreeMap::from([("Content-Type", "text/html"), ("content-type", "text/plain")]);
+    /// let normalized = headers.map_keys(|name| name.to_lowercase());
+    ///
+    /// // Both headers collide; the last one in the order of the original keys wins.
+    /// assert_eq!(normalized, BTreeMap::from([("content-type".to_string(), "text/plain")]));
+    /// ```
+    #[must_use]
+    fn map_keys<K2, F>(self, f: F) -> Self::Map<K2, V>
+    where
+        Self::Map<K2, V>: FromIterator<(K2, V)>,
+        F: FnMut(K) -> K2;
+
+    /// Returns a map with the same values, and keys transformed by the specified function, or a
+    /// [`DuplicateKeyError`] with the first transformed key that collides with a previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let ids = HashMap::from([(1, "Mike"), (2, "Linda")]);
+    /// let by_name = ids.try_map_keys(|id| format!("user-{id}")).unwrap();
+    /// assert_eq!(by_name["user-2"], "Linda");
+    ///
+    /// let users = HashMap::from([("Mike", 1), ("mike", 2)]);
+    /// let error = users.try_map_keys(|name| name.to_lowercase()).unwrap_err();
+    /// assert_eq!(error.key(), "mike");
+    /// ```
+    fn try_map_keys<K2, F>(self, f: F) -> Result<Self::Map<K2, V>, DuplicateKeyError<K2>>
+    where
+        Self::Map<K2, V>: Default + Extend<(K2, V)> + MapLookup<K2, V>,
+        F: FnMut(K) -> K2;
+
+    /// Returns a new map with clones of the entries whose keys match the specified predicate.
+    ///
+    /// This is Kotlin's `filterKeys`; see [`retain_keys`](MapExt::retain_keys) to filter in place instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let env = HashMap::from([("APP_PORT", "8080"), ("APP_HOST", "::1"), ("PATH", "/bin")]);
+    /// let app = env.filter_keys(|key| key.starts_with("APP_"));
+    ///
+    /// assert_eq!(app, HashMap::from([("APP_PORT", "8080"), ("APP_HOST", "::1")]));
+    /// assert_eq!(env.len(), 3);
+    /// ```
+    #[must_use]
+    fn filter_keys<P>(&self, predicate: P) -> Self
+    where
+        Self: FromIterator<(K, V)>,
+        K: Clone,
+        V: Clone,
+        P: FnMut(&K) -> bool;
+
+    /// Returns a new map with clones of the entries whose values match the specified predicate.
+    ///
+    /// This is Kotlin's `filterValues`; see [`retain_values`](MapExt::retain_values) to filter in place instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::MapExt;
+    ///
+    /// let stock = BTreeMap::from([("apple", 12), ("kiwi", 0), ("mango", 3)]);
+    ///
+    /// assert_eq!(stock.filter_values(|&count| count > 0), BTreeMap::from([("apple", 12), ("mango", 3)]));
+    /// ```
+    #[must_use]
+    fn filter_values<P>(&self, predicate: P) -> Self
+    where
+        Self: FromIterator<(K, V)>,
+        K: Clone,
+        V: Clone,
+        P: FnMut(&V) -> bool;
+
+    /// Retains only the entries whose keys match the specified predicate.
+    ///
+    /// This is `retain` without the `(key, value)` tuple in the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let mut sessions = HashMap::from([(101, "Mike"), (205, "Linda"), (150, "Anna")]);
+    /// sessions.retain_keys(|&id| id < 200);
+    ///
+    /// assert_eq!(sessions, HashMap::from([(101, "Mike"), (150, "Anna")]));
+    /// ```
+    fn retain_keys<P>(&mut self, predicate: P)
+    where
+        P: FnMut(&K) -> bool;
+
+    /// Retains only the entries whose values match the specified predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let mut cache = HashMap::from([("a", Some(1)), ("b", None), ("c", Some(3))]);
+    /// cache.retain_values(Option::is_some);
+    ///
+    /// assert_eq!(cache, HashMap::from([("a", Some(1)), ("c", Some(3))]));
+    /// ```
+    fn retain_values<P>(&mut self, predicate: P)
+    where
+        P: FnMut(&V) -> bool;
+
+    /// Returns this map combined with the entries of `other`, resolving duplicate keys with the specified function,
+    /// which receives the key, the value of this map and the value of `other`.
+    ///
+    /// Passing `|_, _, right| right` behaves like Kotlin's `Map.plus`. See [`merge_into`](MapExt::merge_into) to
+    /// merge in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let defaults = HashMap::from([("retries", 3), ("timeout", 30)]);
+    /// let user = HashMap::from([("timeout", 60), ("verbose", 1)]);
+    ///
+    /// let config = defaults.merge(user, |_, _, user| user);
+    /// assert_eq!(config, HashMap::from([("retries", 3), ("timeout", 60), ("verbose", 1)]));
+    ///
+    /// let totals = HashMap::from([("mike", 10)]).merge([("mike", 5), ("anna", 7)], |_, a, b| a + b);
+    /// assert_eq!(totals, HashMap::from([("mike", 15), ("anna", 7)]));
+    /// ```
+    #[must_use]
+    fn merge<I, F>(self, other: I, resolve: F) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&K, V, V) -> V;
+
+    /// Inserts the entries of `other` into this map, resolving duplicate keys with the specified function, which
+    /// receives the key, the value of this map and the value of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::MapExt;
+    ///
+    /// let mut inventory = BTreeMap::from([("apple", 5), ("kiwi", 2)]);
+    /// let delivery = BTreeMap::from([("kiwi", 10), ("mango", 4)]);
+    /// inventory.merge_into(delivery, |_, stock, delivered| stock + delivered);
+    ///
+    /// assert_eq!(inventory, BTreeMap::from([("apple", 5), ("kiwi", 12), ("mango", 4)]));
+    /// ```
+    fn merge_into<I, F>(&mut self, other: I, resolve: F)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        F: FnMut(&K, V, V) -> V;
+
+    /// Wraps this map with a function computing the value for missing keys, returning a [`MapWithDefault`].
+    ///
+    /// This is Kotlin's `withDefault`. The wrapper dereferences to the map for all other operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let mut counts = HashMap::<String, usize>::new().with_default(|_| 0);
+    /// for word in "the cat and the hat".split(' ') {
+    ///     *counts.get_mut(word) += 1;
+    /// }
+    ///
+    /// assert_eq!(counts.get("the"), 2);
+    /// assert_eq!(counts.get("dog"), 0);
+    /// assert_eq!(counts.len(), 4);
+    /// ```
+    fn with_default<F>(self, default: F) -> MapWithDefault<Self, F>
+    where
+        F: Fn(&K) -> V;
+
+    /// Returns a map with the keys and values swapped, for building a reverse lookup table.
+    ///
+    /// If several keys have the same value, only the last of them in iteration order is kept. For a [`HashMap`],
+    /// this order and thus the kept key are unspecified; see [`invert_grouped`](MapExt::invert_grouped) to keep all
+    /// of them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::{BTreeMap, HashMap};
+    /// use kust::MapExt;
+    ///
+    /// let codes = HashMap::from([("OK", 200), ("Not Found", 404)]);
+    /// let names = codes.invert();
+    /// assert_eq!(names[&404], "Not Found");
+    ///
+    /// let aliases = BTreeMap::from([("cp", "copy"), ("mv", "move"), ("copy", "copy")]);
+    /// // Both "copy" and "cp" map to "copy"; the last one in the order of the original keys wins.
+    /// assert_eq!(aliases.invert(), BTreeMap::from([("copy", "cp"), ("move", "mv")]));
+    /// ```
+    #[must_use]
+    fn invert(self) -> Self::Map<V, K>
+    where
+        Self::Map<V, K>: FromIterator<(V, K)>;
+
+    /// Returns a map from each distinct value to all the keys that had it, for inverting maps where several keys
+    /// can have the same value.
+    ///
+    /// The keys of each group are in iteration order, which is unspecified for a [`HashMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::MapExt;
+    ///
+    /// let grades = BTreeMap::from([("Ana", 'A'), ("Ben", 'B'), ("Cleo", 'A')]);
+    /// let students = grades.invert_grouped();
+    ///
+    /// assert_eq!(students[&'A'], ["Ana", "Cleo"]);
+    /// assert_eq!(students[&'B'], ["Ben"]);
+    /// ```
+    #[must_use]
+    fn invert_grouped(self) -> Self::Map<V, Vec<K>>
+    where
+        Self::Map<V, Vec<K>>: Default + Extend<(V, Vec<K>)> + MapLookup<V, Vec<K>>;
+
+    /// Returns a [`BTreeMap`] with clones of the entries of this map, sorted by key.
+    ///
+    /// This is Kotlin's `toSortedMap`, giving a deterministic iteration order, such as for serialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let scores = HashMap::from([("mike", 3), ("ana", 7), ("linda", 5)]);
+    /// let sorted = scores.to_sorted_map();
+    ///
+    /// assert_eq!(sorted.keys().copied().collect::<Vec<_>>(), ["ana", "linda", "mike"]);
+    /// ```
+    #[must_use]
+    fn to_sorted_map(&self) -> BTreeMap<K, V>
+    where
+        K: Ord + Clone,
+        V: Clone;
+
+    /// Returns the entries of this map, sorted by the specified comparator.
+    ///
+    /// The sort is stable, and the entries are borrowed from the map, so that neither keys nor values need to be
+    /// cloned or ordered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let scores = HashMap::from([("mike", 3), ("ana", 7), ("linda", 5)]);
+    /// let ranking = scores.to_sorted_by(|(_, a), (_, b)| b.cmp(a));
+    ///
+    /// assert_eq!(ranking, [(&"ana", &7), (&"linda", &5), (&"mike", &3)]);
+    /// ```
+    #[must_use]
+    fn to_sorted_by<F>(&self, compare: F) -> Vec<(&K, &V)>
+    where
+        F: FnMut(&(&K, &V), &(&K, &V)) -> Ordering;
+
+    /// Returns the value for the given key, or panics with a message naming the key if it is not present.
+    ///
+    /// This is Kotlin's `getValue`, in place of an anonymous `map.get(key).unwrap()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present, with a message containing its [`Debug`] representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let ports = HashMap::from([("http", 80), ("https", 443)]);
+    /// assert_eq!(*ports.get_value("https"), 443);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let ports = HashMap::from([("http", 80), ("https", 443)]);
+    /// ports.get_value("ftp"); // panics
+    /// ```
+    #[must_use]
+    fn get_value<Q>(&self, key: &Q) -> &V
+    where
+        Self: MapLookup<Q, V>,
+        Q: Debug + ?Sized;
+
+    /// Returns the entry with the largest value returned by the specified selector, or `None` if the map is empty.
+    ///
+    /// If several entries are equally maximal, the last one in iteration order is returned, like with
+    /// [`Iterator::max_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::MapExt;
+    ///
+    /// let sizes = HashMap::from([("a.txt", 120), ("b.bin", 4096), ("notes.md", 900)]);
+    ///
+    /// assert_eq!(sizes.max_by_entry(|_, &size| size), Some((&"b.bin", &4096)));
+    /// assert_eq!(sizes.max_by_entry(|name, _| name.len()), Some((&"notes.md", &900)));
+    /// ```
+    #[must_use]
+    fn max_by_entry<R, F>(&self, f: F) -> Option<(&K, &V)>
+    where
+        R: Ord,
+        F: FnMut(&K, &V) -> R;
+
+    /// Returns the entry with the smallest value returned by the specified selector, or `None` if the map is empty.
+    ///
+    /// If several entries are equally minimal, the first one in iteration order is returned, like with
+    /// [`Iterator::min_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::MapExt;
+    ///
+    /// let latencies = BTreeMap::from([("eu", 40), ("us", 95), ("asia", 40)]);
+    /// assert_eq!(latencies.min_by_entry(|_, &ms| ms), Some((&"asia", &40)));
+    /// ```
+    #[must_use]
+    fn min_by_entry<R, F>(&self, f: F) -> Option<(&K, &V)>
+    where
+        R: Ord,
+        F: FnMut(&K, &V) -> R;
+
+    /// Returns up to `n` entries with the largest values returned by the specified selector, largest first.
+    ///
+    /// Equally ranked entries are kept in iteration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use kust::MapExt;
+    ///
+    /// let scores = BTreeMap::from([("ana", 7), ("ben", 9), ("cleo", 7), ("dan", 2)]);
+    /// let podium = scores.top_n_entries(3, |_, &score| score);
+    ///
+    /// assert_eq!(podium, [(&"ben", &9), (&"ana", &7), (&"cleo", &7)]);
+    /// assert_eq!(scores.top_n_entries(10, |_, &score| score).len(), 4);
+    /// ```
+    #[must_use]
+    fn top_n_entries<R, F>(&self, n: usize, f: F) -> Vec<(&K, &V)>
+    where
+        R: Ord,
+        F: FnMut(&K, &V) -> R;
+}
+
+/// Lookup of the maps supported by [`MapExt`] with a borrowed form `Q` of their key.
+///
+/// This abstracts over the bounds required for `Q` by each map: [`Hash`] and [`Eq`] for [`HashMap`], and [`Ord`] for
+/// [`BTreeMap`].
+pub trait MapLookup<Q: ?Sized, V> {
+    /// Returns a reference to the value for the given key, like [`HashMap::get`].
+    fn lookup(&self, key: &Q) -> Option<&V>;
+
+    /// Returns a mutable reference to the value for the given key, like [`HashMap::get_mut`].
+    fn lookup_mut(&mut self, key: &Q) -> Option<&mut V>;
+}
+
+impl<K, V, S, Q> MapLookup<Q, V> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    #[inline]
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+
+    #[inline]
+    fn lookup_mut(&mut self, key: &Q) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+impl<K, V, Q> MapLookup<Q, V> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    #[inline]
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+
+    #[inline]
+    fn lookup_mut(&mut self, key: &Q) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+}
+
+/// Expands to the implementation of [`MapExt`], which is the same for all supported maps, as they share the names of
+/// their inherent methods. Only the types of the entry API differ, so the `std::collections` module defining them is
+/// passed.
+macro_rules! map_ext_methods {
+    ($module:ident) => {
+        #[inline]
+        fn get_or_put<Q, F>(&mut self, key: &Q, f: F) -> &mut V
+        where
+            Self: MapLookup<Q, V>,
+            Q: ToOwned<Owned = K> + ?Sized,
+            F: FnOnce() -> V,
+        {
+            // Returning the result of `lookup_mut` early when present isn't accepted by the borrow checker yet.
+            if self.lookup(key).is_none() {
+                self.insert(key.to_owned(), f());
+            }
+            self.lookup_mut(key).expect("the key was just inserted")
+        }
+
+        #[inline]
+        fn get_or_else<Q, F>(&self, key: &Q, f: F) -> V
+        where
+            Self: MapLookup<Q, V>,
+            Q: ?Sized,
+            V: Clone,
+            F: FnOnce() -> V,
+        {
+            self.lookup(key).cloned().unwrap_or_else(f)
+        }
+
+        #[inline]
+        fn get_or_default<Q>(&self, key: &Q) -> V
+        where
+            Self: MapLookup<Q, V>,
+            Q: ?Sized,
+            V: Default + Clone,
+        {
+            self.lookup(key).cloned().unwrap_or_default()
+        }
+
+        #[inline]
+        fn map_values<V2, F>(self, mut f: F) -> Self::Map<K, V2>
+        where
+            Self::Map<K, V2>: FromIterator<(K, V2)>,
+            F: FnMut(V) -> V2,
+        {
+            self.into_iter().map(|(key, value)| (key, f(value))).collect()
+        }
+
+        #[inline]
+        fn map_keys<K2, F>(self, mut f: F) -> Self::Map<K2, V>
+        where
+            Self::Map<K2, V>: FromIterator<(K2, V)>,
+            F: FnMut(K) -> K2,
+        {
+            self.into_iter().map(|(key, value)| (f(key), value)).collect()
+        }
+
+        #[inline]
+        fn try_map_keys<K2, F>(self, mut f: F) -> Result<Self::Map<K2, V>, DuplicateKeyError<K2>>
+        where
+            Self::Map<K2, V>: Default + Extend<(K2, V)> + MapLookup<K2, V>,
+            F: FnMut(K) -> K2,
+        {
+            let mut map = Self::Map::<K2, V>::default();
+            for (key, value) in self {
+                let key = f(key);
+                if map.lookup(&key).is_some() {
+                    return Err(DuplicateKeyError { key });
+                }
+                map.extend(Some((key, value)));
+            }
+            Ok(map)
+        }
+
+        #[inline]
+        fn filter_keys<P>(&self, mut predicate: P) -> Self
+        where
+            Self: FromIterator<(K, V)>,
+            K: Clone,
+            V: Clone,
+            P: FnMut(&K) -> bool,
+        {
+            self.iter().filter(|(key, _)| predicate(key)).map(|(key, value)| (key.clone(), value.clone())).collect()
+        }
+
+        #[inline]
+        fn filter_values<P>(&self, mut predicate: P) -> Self
+        where
+            Self: FromIterator<(K, V)>,
+            K: Clone,
+            V: Clone,
+            P: FnMut(&V) -> bool,
+        {
+            self.iter().filter(|(_, value)| predicate(value)).map(|(key, value)| (key.clone(), value.clone())).collect()
+        }
+
+        #[inline]
+        fn retain_keys<P>(&mut self, mut predicate: P)
+        where
+            P: FnMut(&K) -> bool,
+        {
+            self.retain(|key, _| predicate(key));
+        }
+
+        #[inline]
+        fn retain_values<P>(&mut self, mut predicate: P)
+        where
+            P: FnMut(&V) -> bool,
+        {
+            self.retain(|_, value| predicate(value));
+        }
+
+        #[inline]
+        fn merge<I, F>(mut self, other: I, resolve: F) -> Self
+        where
+            I: IntoIterator<Item = (K, V)>,
+            F: FnMut(&K, V, V) -> V,
+        {
+            self.merge_into(other, resolve);
+            self
+        }
+
+        fn merge_into<I, F>(&mut self, other: I, mut resolve: F)
+        where
+            I: IntoIterator<Item = (K, V)>,
+            F: FnMut(&K, V, V) -> V,
+        {
+            for (key, value) in other {
+                match self.entry(key) {
+                    std::collections::$module::Entry::Occupied(entry) => {
+                        // `resolve` consumes the existing value, so it has to be taken out of the map.
+                        let (key, existing) = entry.remove_entry();
+                        let value = resolve(&key, existing, value);
+                        self.insert(key, value);
+                    }
+                    std::collections::$module::Entry::Vacant(entry) => {
+                        entry.insert(value);
+                    }
+                }
+            }
+        }
+
+        #[inline]
+        fn with_default<F>(self, default: F) -> MapWithDefault<Self, F>
+        where
+            F: Fn(&K) -> V,
+        {
+            MapWithDefault { map: self, default }
+        }
+
+        #[inline]
+        fn invert(self) -> Self::Map<V, K>
+        where
+            Self::Map<V, K>: FromIterator<(V, K)>,
+        {
+            self.into_iter().map(|(key, value)| (value, key)).collect()
+        }
+
+        #[inline]
+        fn invert_grouped(self) -> Self::Map<V, Vec<K>>
+        where
+            Self::Map<V, Vec<K>>: Default + Extend<(V, Vec<K>)> + MapLookup<V, Vec<K>>,
+        {
+            let mut map = Self::Map::<V, Vec<K>>::default();
+            for (key, value) in self {
+                match map.lookup_mut(&value) {
+                    Some(keys) => keys.push(key),
+                    None => map.extend(Some((value, vec![key]))),
+                }
+            }
+            map
+        }
+
+        #[inline]
+        fn to_sorted_map(&self) -> BTreeMap<K, V>
+        where
+            K: Ord + Clone,
+            V: Clone,
+        {
+            self.iter().map(|(key, value)| (key.clone(), value.clone())).collect()
+        }
+
+        #[inline]
+        fn to_sorted_by<F>(&self, compare: F) -> Vec<(&K, &V)>
+        where
+            F: FnMut(&(&K, &V), &(&K, &V)) -> Ordering,
+        {
+            let mut entries: Vec<_> = self.iter().collect();
+            entries.sort_by(compare);
+            entries
+        }
+
+        #[inline]
+        #[track_caller]
+        fn get_value<Q>(&self, key: &Q) -> &V
+        where
+            Self: MapLookup<Q, V>,
+            Q: Debug + ?Sized,
+        {
+            match self.lookup(key) {
+                Some(value) => value,
+                None => panic!("key {key:?} is missing in the map"),
+            }
+        }
+
+        #[inline]
+        fn max_by_entry<R, F>(&self, mut f: F) -> Option<(&K, &V)>
+        where
+            R: Ord,
+            F: FnMut(&K, &V) -> R,
+        {
+            self.iter().max_by_key(|&(key, value)| f(key, value))
+        }
+
+        #[inline]
+        fn min_by_entry<R, F>(&self, mut f: F) -> Option<(&K, &V)>
+        where
+            R: Ord,
+            F: FnMut(&K, &V) -> R,
+        {
+            self.iter().min_by_key(|&(key, value)| f(key, value))
+        }
+
+        #[inline]
+        fn top_n_entries<R, F>(&self, n: usize, mut f: F) -> Vec<(&K, &V)>
+        where
+            R: Ord,
+            F: FnMut(&K, &V) -> R,
+        {
+            let mut entries: Vec<_> = self.iter().map(|(key, value)| (f(key, value), key, value)).collect();
+            entries.sort_by(|(a, ..), (b, ..)| b.cmp(a));
+            entries.into_iter().take(n).map(|(_, key, value)| (key, value)).collect()
+        }
+    };
+}
+
+impl<K, V, S> MapExt<K, V> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Map<K2, V2> = HashMap<K2, V2, S>;
+
+    map_ext_methods!(hash_map);
+}
+
+impl<K, V> MapExt<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type Map<K2, V2> = BTreeMap<K2, V2>;
+
+    map_ext_methods!(btree_map);
+}
+
+/// A map with a function computing the value for missing keys, created by [`with_default`](MapExt::with_default).
+///
+/// [`get`](MapWithDefault::get) never fails, and [`get_mut`](MapWithDefault::get_mut) inserts the default value
+/// first when the key is missing. The wrapper dereferences to the underlying map for all other operations.
+#[must_use]
+pub struct MapWithDefault<M, F> {
+    map: M,
+    default: F,
+}
+
+impl<M, F> MapWithDefault<M, F> {
+    /// Returns a clone of the value for the given key, or the default value for it if the key is missing.
+    ///
+    /// The map is not modified. This is Kotlin's `getValue` on a map created by `withDefault`.
+    ///
+    /// Indexing with `map[key]` doesn't use the default: it goes through [`Deref`] to the underlying map, so it still
+    /// panics if the key is missing.
+    #[inline]
+    #[must_use]
+    pub fn get<K, V, Q>(&self, key: &Q) -> V
+    where
+        M: MapLookup<Q, V>,
+        Q: ToOwned<Owned = K> + ?Sized,
+        V: Clone,
+        F: Fn(&K) -> V,
+    {
+        match self.map.lookup(key) {
+            Some(value) => value.clone(),
+            None => (self.default)(&key.to_owned()),
+        }
+    }
+
+    /// Returns a mutable reference to the value for the given key, first inserting the default value for it if the
+    /// key is missing.
+    ///
+    /// The key is only cloned into an owned key when it is missing.
+    #[inline]
+    pub fn get_mut<K, V, Q>(&mut self, key: &Q) -> &mut V
+    where
+        M: MapLookup<Q, V> + Extend<(K, V)>,
+        Q: ToOwned<Owned = K> + ?Sized,
+        F: Fn(&K) -> V,
+    {
+        if self.map.lookup(key).is_none() {
+            let key = key.to_owned();
+            let value = (self.default)(&key);
+            self.map.extend(Some((key, value)));
+        }
+        self.map.lookup_mut(key).expect("the key was just inserted")
+    }
+
+    /// Returns the underlying map, discarding the default function.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> M {
+        self.map
+    }
+}
+
+impl<M, F> Deref for MapWithDefault<M, F> {
+    type Target = M;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<M, F> DerefMut for MapWithDefault<M, F> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.map
+    }
+}
+
+/// The error returned by [`try_map_keys`](MapExt::try_map_keys) when two keys are transformed into the same key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DuplicateKeyError<K> {
+    key: K,
+}
+
+impl<K> DuplicateKeyError<K> {
+    /// Returns the duplicate key.
+    #[inline]
+    #[must_use]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the duplicate key, consuming the error.
+    #[inline]
+    #[must_use]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<K: Debug> Display for DuplicateKeyError<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "duplicate key: {:?}", self.key)
+    }
+}
+
+impl<K: Debug> Error for DuplicateKeyError<K> {}
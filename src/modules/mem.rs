@@ -0,0 +1,59 @@
+use std::{mem, process, ptr};
+
+pub trait MutExt {
+    /// Replaces the value behind this mutable reference with the result of calling the specified function with the
+    /// current value.
+    ///
+    /// Use `replace_with` to fluently transform a value you only have a mutable reference to, such as a struct field,
+    /// without requiring [`Default`] for [`std::mem::take`].
+    ///
+    /// # Panics
+    ///
+    /// As the value is temporarily moved out, there is nothing valid left behind if the function panics. To avoid
+    /// exposing the moved-out value, the process is **aborted** if the function panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::MutExt;
+    ///
+    /// struct Document {
+    ///     title: String,
+    /// }
+    ///
+    /// fn shout(doc: &mut Document) {
+    ///     doc.title.replace_with(|t| t.to_uppercase() + "!");
+    /// }
+    ///
+    /// let mut doc = Document { title: String::from("hello") };
+    /// shout(&mut doc);
+    ///
+    /// assert_eq!(doc.title, "HELLO!");
+    /// ```
+    #[inline]
+    fn replace_with<F>(&mut self, f: F)
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> Self,
+    {
+        struct AbortOnPanic;
+
+        impl Drop for AbortOnPanic {
+            fn drop(&mut self) {
+                process::abort();
+            }
+        }
+
+        // SAFETY: the value is read out of `self` and a new value is written back before `self` can be observed again.
+        // Should `f` panic, the guard aborts the process while unwinding, so the moved-out value is never dropped twice.
+        unsafe {
+            let old = ptr::read(self);
+            let guard = AbortOnPanic;
+            let new = f(old);
+            mem::forget(guard);
+            ptr::write(self, new);
+        }
+    }
+}
+
+impl<T> MutExt for T {}
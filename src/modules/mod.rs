@@ -1,3 +1,45 @@
+#[cfg(feature = "async")]
+mod async_scope_functions;
+mod collection;
+mod either;
+mod iter;
+mod lock;
+mod macros;
+mod map;
+mod mem;
+mod option;
+mod panic;
+mod preconditions;
+#[cfg(feature = "rand")]
+mod random;
+mod result;
 mod scope_functions;
+mod sequence;
+mod set;
+mod slice;
+mod standalone;
+mod string;
+mod vec;
 
+#[cfg(feature = "async")]
+pub use async_scope_functions::*;
+pub use collection::*;
+pub use either::*;
+pub use iter::*;
+pub use lock::*;
+pub use macros::*;
+pub use map::*;
+pub use mem::*;
+pub use option::*;
+pub use panic::*;
+pub use preconditions::*;
+#[cfg(feature = "rand")]
+pub use random::*;
+pub use result::*;
 pub use scope_functions::*;
+pub use sequence::*;
+pub use set::*;
+pub use slice::*;
+pub use standalone::*;
+pub use string::*;
+pub use vec::*;
@@ -0,0 +1,216 @@
+use std::fmt::Display;
+
+use crate::CollectionExt;
+
+/// Kotlin-style extensions for [`Option`].
+pub trait OptionExt<T> {
+    /// Calls the specified function if this is `None`, and returns the original option.
+    ///
+    /// Use `on_none` to log or record the absent case without breaking the chain. This is equivalent to
+    /// [`also_none`](crate::OptionScope::also_none).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::OptionExt;
+    ///
+    /// let settings = HashMap::from([("theme", "dark")]);
+    /// let mut warnings = Vec::new();
+    ///
+    /// let language = settings.get("language").on_none(|| warnings.push("language not set")).unwrap_or(&"en");
+    ///
+    /// assert_eq!(*language, "en");
+    /// assert_eq!(warnings, ["language not set"]);
+    /// ```
+    #[must_use]
+    fn on_none<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce() -> R;
+
+    /// Returns the contained value, or panics with the message returned by the specified function if this is `None`.
+    ///
+    /// Unlike [`Option::expect`], the message is only built when the option is `None`, so it can be expensive to
+    /// format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is `None`, with the returned message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::OptionExt;
+    ///
+    /// let ids = [10, 20, 30];
+    /// let wanted = 20;
+    ///
+    /// let index = ids.iter().position(|&id| id == wanted).expect_lazy(|| format!("id {wanted} not in {ids:?}"));
+    /// assert_eq!(index, 1);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use kust::OptionExt;
+    ///
+    /// let ids = [10, 20, 30];
+    /// ids.iter().position(|&id| id == 40).expect_lazy(|| format!("id 40 not in {ids:?}")); // panics
+    /// ```
+    #[must_use]
+    fn expect_lazy<F, M>(self, f: F) -> T
+    where
+        F: FnOnce() -> M,
+        M: Display;
+
+    /// Zips this option with two other options, returning `Some` of a tuple if all three are `Some`, or `None`
+    /// otherwise.
+    ///
+    /// See the [`zip_all!`](crate::zip_all) macro for any number of options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::OptionExt;
+    ///
+    /// let (r, g, b) = (Some(255u8), Some(128u8), Some(0u8));
+    ///
+    /// assert_eq!(r.zip3(g, b), Some((255, 128, 0)));
+    /// assert_eq!(r.zip3(None::<u8>, b), None);
+    /// ```
+    #[must_use]
+    fn zip3<B, C>(self, b: Option<B>, c: Option<C>) -> Option<(T, B, C)>;
+
+    /// Combines this option with another one using the specified function, returning `Some` of the result if both are
+    /// `Some`, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::OptionExt;
+    ///
+    /// let width: Option<u32> = "800".parse().ok();
+    /// let height: Option<u32> = "600".parse().ok();
+    ///
+    /// assert_eq!(OptionExt::zip_with(width, height, |w, h| w * h), Some(480_000));
+    /// assert_eq!(OptionExt::zip_with(width, None, |w, h: u32| w * h), None);
+    /// ```
+    ///
+    /// Note that `Option` has an unstable inherent method with the same name, so calling this with method syntax
+    /// causes an `unstable_name_collisions` warning. Use the fully-qualified syntax shown above to avoid it.
+    #[must_use]
+    fn zip_with<U, R, F>(self, other: Option<U>, f: F) -> Option<R>
+    where
+        F: FnOnce(T, U) -> R;
+
+    /// Returns the contained collection, or an empty one if this is `None`.
+    ///
+    /// This is Kotlin's `orEmpty`, and is equivalent to [`Option::unwrap_or_default`] limited to collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use kust::OptionExt;
+    ///
+    /// let groups = HashMap::from([("admins", vec!["root"])]);
+    ///
+    /// assert_eq!(groups.get("admins").cloned().or_empty(), ["root"]);
+    /// assert!(groups.get("guests").cloned().or_empty().is_empty());
+    /// assert_eq!(None::<&str>.or_empty(), "");
+    /// ```
+    #[must_use]
+    fn or_empty(self) -> T
+    where
+        T: CollectionExt + Default;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[inline]
+    fn on_none<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce() -> R,
+    {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_lazy<F, M>(self, f: F) -> T
+    where
+        F: FnOnce() -> M,
+        M: Display,
+    {
+        match self {
+            Some(value) => value,
+            None => panic!("{}", f()),
+        }
+    }
+
+    #[inline]
+    fn zip3<B, C>(self, b: Option<B>, c: Option<C>) -> Option<(T, B, C)> {
+        match (self, b, c) {
+            (Some(a), Some(b), Some(c)) => Some((a, b, c)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn zip_with<U, R, F>(self, other: Option<U>, f: F) -> Option<R>
+    where
+        F: FnOnce(T, U) -> R,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn or_empty(self) -> T
+    where
+        T: CollectionExt + Default,
+    {
+        self.unwrap_or_default()
+    }
+}
+
+/// Zips any number of [`Option`]s, returning `Some` of a tuple if all of them are `Some`, or `None` otherwise.
+///
+/// The options are evaluated left-to-right, stopping at the first `None`. They are evaluated in the caller's
+/// context, not in a closure, so they may use `?`, `return` or `.await`, which apply to the enclosing function.
+///
+/// # Examples
+///
+/// ```
+/// use kust::zip_all;
+///
+/// let name = Some("Mike");
+/// let age = Some(34);
+/// let city = Some("Paris");
+///
+/// assert_eq!(zip_all!(name, age, city, Some(true)), Some(("Mike", 34, "Paris", true)));
+/// assert_eq!(zip_all!(name, None::<u32>, city), None);
+///
+/// fn parse_point(x: &str, y: Option<&str>) -> Result<Option<(i32, i32)>, std::num::ParseIntError> {
+///     Ok(zip_all!(Some(x.parse()?), y.map(str::parse).transpose()?))
+/// }
+///
+/// assert_eq!(parse_point("3", Some("4")), Ok(Some((3, 4))));
+/// assert_eq!(parse_point("3", None), Ok(None));
+/// assert!(parse_point("three", Some("4")).is_err());
+/// ```
+#[macro_export]
+macro_rules! zip_all {
+    ($($option:expr),+ $(,)?) => {
+        'zip: {
+            ::core::option::Option::Some(($(
+                match $option {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => break 'zip ::core::option::Option::None,
+                },
+            )+))
+        }
+    };
+}
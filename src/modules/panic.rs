@@ -0,0 +1,163 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic;
+
+/// A panic caught by [`run_catching`](crate::run_catching) and other catching functions.
+///
+/// Wraps the panic payload, providing access to the panic message when it is a string, which is the case for panics
+/// created using [`panic!`] with a message.
+pub struct PanicError {
+    payload: Box<dyn Any + Send + 'static>,
+}
+
+impl PanicError {
+    /// Creates a new panic error from the given panic payload, as returned by [`std::panic::catch_unwind`].
+    #[inline]
+    #[must_use]
+    pub fn new(payload: Box<dyn Any + Send + 'static>) -> Self {
+        Self { payload }
+    }
+
+    /// Returns the panic message, if the payload is a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::run_catching;
+    ///
+    /// let error = run_catching(|| panic!("out of {}", "cheese")).unwrap_err();
+    ///
+    /// assert_eq!(error.message(), Some("out of cheese"));
+    /// ```
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        if let Some(message) = self.payload.downcast_ref::<&'static str>() {
+            Some(message)
+        } else {
+            self.payload.downcast_ref::<String>().map(String::as_str)
+        }
+    }
+
+    /// Returns a reference to the panic payload.
+    #[inline]
+    #[must_use]
+    pub fn payload(&self) -> &(dyn Any + Send + 'static) {
+        &*self.payload
+    }
+
+    /// Returns the panic payload.
+    #[inline]
+    #[must_use]
+    pub fn into_payload(self) -> Box<dyn Any + Send + 'static> {
+        self.payload
+    }
+
+    /// Resumes unwinding with the original panic payload, using [`std::panic::resume_unwind`].
+    #[inline]
+    pub fn resume(self) -> ! {
+        panic::resume_unwind(self.payload)
+    }
+}
+
+impl From<Box<dyn Any + Send + 'static>> for PanicError {
+    #[inline]
+    fn from(payload: Box<dyn Any + Send + 'static>) -> Self {
+        Self::new(payload)
+    }
+}
+
+impl Debug for PanicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PanicError").field("message", &self.message()).finish_non_exhaustive()
+    }
+}
+
+impl Display for PanicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.message() {
+            Some(message) => write!(f, "panicked: {message}"),
+            None => f.write_str("panicked"),
+        }
+    }
+}
+
+impl Error for PanicError {}
+
+/// An error that is either a regular error or a caught panic, returned by catching functions such as
+/// [`map_catching`](crate::ResultExt::map_catching).
+///
+/// It is displayed as the error it contains, and has the same [source](Error::source).
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use kust::CatchingError;
+///
+/// let error: CatchingError<_> = CatchingError::Error("x".parse::<i32>().unwrap_err());
+/// assert_eq!(error.to_string(), "invalid digit found in string");
+/// assert!(error.source().is_none());
+/// ```
+#[derive(Debug)]
+pub enum CatchingError<E> {
+    /// A regular error, returned as `Err`.
+    Error(E),
+    /// A caught panic.
+    Panic(PanicError),
+}
+
+impl<E> CatchingError<E> {
+    /// Returns the regular error, or `None` if this is a caught panic.
+    #[inline]
+    #[must_use]
+    pub fn error(self) -> Option<E> {
+        match self {
+            CatchingError::Error(error) => Some(error),
+            CatchingError::Panic(_) => None,
+        }
+    }
+
+    /// Returns the caught panic, or `None` if this is a regular error.
+    #[inline]
+    #[must_use]
+    pub fn panic(self) -> Option<PanicError> {
+        match self {
+            CatchingError::Error(_) => None,
+            CatchingError::Panic(panic) => Some(panic),
+        }
+    }
+
+    /// Returns `true` if this is a caught panic.
+    #[inline]
+    #[must_use]
+    pub fn is_panic(&self) -> bool {
+        matches!(self, CatchingError::Panic(_))
+    }
+}
+
+impl<E> From<PanicError> for CatchingError<E> {
+    #[inline]
+    fn from(panic: PanicError) -> Self {
+        CatchingError::Panic(panic)
+    }
+}
+
+impl<E: Display> Display for CatchingError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatchingError::Error(error) => Display::fmt(error, f),
+            CatchingError::Panic(panic) => Display::fmt(panic, f),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for CatchingError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // Transparent: the contained error is already displayed, so it isn't a source of its own.
+        match self {
+            CatchingError::Error(error) => error.source(),
+            CatchingError::Panic(panic) => panic.source(),
+        }
+    }
+}
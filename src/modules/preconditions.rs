@@ -0,0 +1,302 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The kind of precondition that failed, determining the default message of a [`PreconditionError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreconditionKind {
+    /// A requirement on the arguments, checked by [`require!`](crate::require) and
+    /// [`try_require!`](crate::try_require).
+    Require,
+    /// A check of the state, checked by [`check!`](crate::check) and [`try_check!`](crate::try_check).
+    Check,
+}
+
+/// A lightweight error returned by the precondition macros, such as [`try_require!`](crate::try_require).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PreconditionError {
+    kind: PreconditionKind,
+    message: Option<String>,
+}
+
+impl PreconditionError {
+    /// Creates a new precondition error of the given kind, with an optional message.
+    #[inline]
+    #[must_use]
+    pub fn new(kind: PreconditionKind, message: Option<String>) -> Self {
+        Self { kind, message }
+    }
+
+    /// Returns the kind of precondition that failed.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> PreconditionKind {
+        self.kind
+    }
+
+    /// Returns the message of this error, if one was given.
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl Display for PreconditionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.message, self.kind) {
+            (Some(message), _) => f.write_str(message),
+            (None, PreconditionKind::Require) => f.write_str("Failed requirement."),
+            (None, PreconditionKind::Check) => f.write_str("Check failed."),
+        }
+    }
+}
+
+impl Error for PreconditionError {}
+
+/// Panics if the given requirement on the arguments doesn't hold, with an optional formatted message.
+///
+/// This is Kotlin's `require`. The message is only formatted if the requirement fails. See
+/// [`try_require!`](crate::try_require) for returning an error instead.
+///
+/// # Examples
+///
+/// ```should_panic
+/// use kust::require;
+///
+/// fn set_volume(volume: u8) {
+///     require!(volume <= 100, "volume must be at most 100, was {volume}");
+///     // ...
+/// }
+///
+/// set_volume(150); // panics
+/// ```
+#[macro_export]
+macro_rules! require {
+    ($condition:expr $(,)?) => {
+        if !$condition {
+            ::core::panic!("Failed requirement.")
+        }
+    };
+    ($condition:expr, $($arg:tt)+) => {
+        if !$condition {
+            ::core::panic!($($arg)+)
+        }
+    };
+}
+
+/// Panics if the given check of the state doesn't hold, with an optional formatted message.
+///
+/// This is Kotlin's `check`. The message is only formatted if the check fails. See [`try_check!`](crate::try_check)
+/// for returning an error instead.
+///
+/// # Examples
+///
+/// ```should_panic
+/// use kust::check;
+///
+/// struct Connection {
+///     open: bool,
+/// }
+///
+/// impl Connection {
+///     fn send(&self, data: &str) {
+///         check!(self.open, "cannot send {} bytes on a closed connection", data.len());
+///         // ...
+///     }
+/// }
+///
+/// Connection { open: false }.send("hello"); // panics
+/// ```
+#[macro_export]
+macro_rules! check {
+    ($condition:expr $(,)?) => {
+        if !$condition {
+            ::core::panic!("Check failed.")
+        }
+    };
+    ($condition:expr, $($arg:tt)+) => {
+        if !$condition {
+            ::core::panic!($($arg)+)
+        }
+    };
+}
+
+/// Returns early with a [`PreconditionError`] if the given requirement on the arguments doesn't hold, with an
+/// optional formatted message.
+///
+/// The error is converted using [`From`], so it can be returned from functions with any error type implementing
+/// `From<PreconditionError>`, such as `Box<dyn Error>`. The message is only formatted if the requirement fails.
+///
+/// # Examples
+///
+/// ```
+/// use kust::{try_require, PreconditionError};
+///
+/// fn parse_percentage(value: i32) -> Result<u8, PreconditionError> {
+///     try_require!(value >= 0, "percentage can't be negative, was {value}");
+///     try_require!(value <= 100);
+///     Ok(value as u8)
+/// }
+///
+/// assert_eq!(parse_percentage(42), Ok(42));
+/// assert_eq!(parse_percentage(-5).unwrap_err().to_string(), "percentage can't be negative, was -5");
+/// assert_eq!(parse_percentage(150).unwrap_err().to_string(), "Failed requirement.");
+/// ```
+#[macro_export]
+macro_rules! try_require {
+    ($condition:expr $(,)?) => {
+        if !$condition {
+            return ::core::result::Result::Err(::core::convert::From::from($crate::PreconditionError::new(
+                $crate::PreconditionKind::Require,
+                ::core::option::Option::None,
+            )));
+        }
+    };
+    ($condition:expr, $($arg:tt)+) => {
+        if !$condition {
+            return ::core::result::Result::Err(::core::convert::From::from($crate::PreconditionError::new(
+                $crate::PreconditionKind::Require,
+                ::core::option::Option::Some(::std::format!($($arg)+)),
+            )));
+        }
+    };
+}
+
+/// Returns early with a [`PreconditionError`] if the given check of the state doesn't hold, with an optional
+/// formatted message.
+///
+/// See [`try_require!`](crate::try_require) for details.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use kust::{try_check, PreconditionError, PreconditionKind};
+///
+/// fn pop_twice(stack: &mut Vec<i32>) -> Result<(i32, i32), Box<dyn Error>> {
+///     try_check!(stack.len() >= 2, "stack has only {} elements", stack.len());
+///     Ok((stack.pop().unwrap(), stack.pop().unwrap()))
+/// }
+///
+/// assert_eq!(pop_twice(&mut vec![1, 2, 3]).unwrap(), (3, 2));
+///
+/// let error = pop_twice(&mut vec![1]).unwrap_err();
+/// let error = error.downcast_ref::<PreconditionError>().unwrap();
+/// assert_eq!(error.kind(), PreconditionKind::Check);
+/// assert_eq!(error.message(), Some("stack has only 1 elements"));
+/// ```
+#[macro_export]
+macro_rules! try_check {
+    ($condition:expr $(,)?) => {
+        if !$condition {
+            return ::core::result::Result::Err(::core::convert::From::from($crate::PreconditionError::new(
+                $crate::PreconditionKind::Check,
+                ::core::option::Option::None,
+            )));
+        }
+    };
+    ($condition:expr, $($arg:tt)+) => {
+        if !$condition {
+            return ::core::result::Result::Err(::core::convert::From::from($crate::PreconditionError::new(
+                $crate::PreconditionKind::Check,
+                ::core::option::Option::Some(::std::format!($($arg)+)),
+            )));
+        }
+    };
+}
+
+/// Unwraps an [`Option`], panicking with the given formatted message if it is `None`.
+///
+/// Unlike [`Option::expect`], the message supports format arguments and is only formatted if the option is `None`.
+/// As this is a macro, the panic location is always that of the caller.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use kust::expect_some;
+///
+/// let ports = HashMap::from([("http", 80), ("https", 443)]);
+/// let scheme = "https";
+///
+/// let port = expect_some!(ports.get(scheme), "no default port for {scheme}");
+/// assert_eq!(*port, 443);
+/// ```
+#[macro_export]
+macro_rules! expect_some {
+    ($option:expr, $($arg:tt)+) => {
+        match $option {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!($($arg)+),
+        }
+    };
+}
+
+/// Unwraps an [`Option`], panicking with an optional formatted message if it is `None`.
+///
+/// This is Kotlin's `requireNotNull`, panicking with "Required value was null." if no message is given. The message
+/// is only formatted if the option is `None`. See [`ok_or_msg!`](crate::ok_or_msg) for returning an error instead.
+///
+/// # Examples
+///
+/// ```should_panic
+/// use kust::require_not_none;
+///
+/// fn greet(name: Option<&str>) -> String {
+///     let name = require_not_none!(name, "a name is required to greet");
+///     format!("Hi, {name}!")
+/// }
+///
+/// assert_eq!(greet(Some("Mike")), "Hi, Mike!");
+/// greet(None); // panics
+/// ```
+#[macro_export]
+macro_rules! require_not_none {
+    ($option:expr $(,)?) => {
+        match $option {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!("Required value was null."),
+        }
+    };
+    ($option:expr, $($arg:tt)+) => {
+        match $option {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!($($arg)+),
+        }
+    };
+}
+
+/// Converts an [`Option`] into a `Result<T, PreconditionError>`, with the given formatted message as the error if it
+/// is `None`.
+///
+/// The message is only formatted if the option is `None`. Combined with `?`, this covers Kotlin's
+/// `requireNotNull(x) { "..." }` in functions returning errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use kust::{ok_or_msg, PreconditionError};
+///
+/// fn lookup(users: &HashMap<u32, &str>, id: u32) -> Result<String, PreconditionError> {
+///     let name = ok_or_msg!(users.get(&id), "no user with id {id}")?;
+///     Ok(name.to_uppercase())
+/// }
+///
+/// let users = HashMap::from([(1, "mike")]);
+///
+/// assert_eq!(lookup(&users, 1).unwrap(), "MIKE");
+/// assert_eq!(lookup(&users, 2).unwrap_err().to_string(), "no user with id 2");
+/// ```
+#[macro_export]
+macro_rules! ok_or_msg {
+    ($option:expr, $($arg:tt)+) => {
+        match $option {
+            ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+            ::core::option::Option::None => ::core::result::Result::Err($crate::PreconditionError::new(
+                $crate::PreconditionKind::Require,
+                ::core::option::Option::Some(::std::format!($($arg)+)),
+            )),
+        }
+    };
+}
@@ -0,0 +1,181 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Kotlin-style random extensions for slices, available with the `rand` feature.
+///
+/// Each function uses [`rand::thread_rng`], and has a `_with` variant accepting a specific random number generator,
+/// such as a seeded one for reproducible results. Note that [`SliceRandom`] has methods with some of the same names,
+/// so importing both traits requires the fully-qualified syntax for those.
+pub trait RandomExt<T> {
+    /// Returns a random element.
+    ///
+    /// This is Kotlin's `random`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::RandomExt;
+    ///
+    /// let dice = [1, 2, 3, 4, 5, 6];
+    ///
+    /// assert!(dice.contains(dice.random()));
+    /// ```
+    #[must_use]
+    fn random(&self) -> &T;
+
+    /// Returns a random element, chosen with the specified random number generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use kust::RandomExt;
+    ///
+    /// let colors = ["red", "green", "blue"];
+    /// let first = *colors.random_with(&mut StdRng::seed_from_u64(42));
+    ///
+    /// assert_eq!(*colors.random_with(&mut StdRng::seed_from_u64(42)), first);
+    /// ```
+    #[must_use]
+    fn random_with<R: Rng + ?Sized>(&self, rng: &mut R) -> &T;
+
+    /// Returns a random element, or [`None`] if the slice is empty.
+    ///
+    /// This is Kotlin's `randomOrNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::RandomExt;
+    ///
+    /// let empty: [u8; 0] = [];
+    ///
+    /// assert_eq!([7].random_or_none(), Some(&7));
+    /// assert_eq!(empty.random_or_none(), None);
+    /// ```
+    #[must_use]
+    fn random_or_none(&self) -> Option<&T>;
+
+    /// Returns a random element chosen with the specified random number generator, or [`None`] if the slice is
+    /// empty.
+    #[must_use]
+    fn random_or_none_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T>;
+
+    /// Shuffles the elements in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::RandomExt;
+    ///
+    /// let mut deck: Vec<u8> = (1..=52).collect();
+    /// deck.shuffle();
+    /// deck.sort();
+    ///
+    /// assert!(deck.iter().copied().eq(1..=52));
+    /// ```
+    fn shuffle(&mut self);
+
+    /// Shuffles the elements in place with the specified random number generator.
+    fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R);
+
+    /// Returns a shuffled copy of the elements, leaving the slice untouched.
+    ///
+    /// This is Kotlin's `shuffled`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::RandomExt;
+    ///
+    /// let players = vec!["Mike", "Linda", "Anna"];
+    /// let mut order = players.shuffled();
+    /// order.sort();
+    ///
+    /// assert_eq!(order, ["Anna", "Linda", "Mike"]);
+    /// ```
+    #[must_use]
+    fn shuffled(&self) -> Vec<T>
+    where
+        T: Clone;
+
+    /// Returns a copy of the elements shuffled with the specified random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use kust::RandomExt;
+    ///
+    /// let cards = [1, 2, 3, 4, 5];
+    ///
+    /// // The same seed always produces the same order.
+    /// let order = cards.shuffled_with(&mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(cards.shuffled_with(&mut StdRng::seed_from_u64(7)), order);
+    /// ```
+    #[must_use]
+    fn shuffled_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<T>
+    where
+        T: Clone;
+}
+
+impl<T> RandomExt<T> for [T] {
+    #[inline]
+    fn random(&self) -> &T {
+        self.random_with(&mut rand::thread_rng())
+    }
+
+    #[inline]
+    fn random_with<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        self.random_or_none_with(rng).expect("cannot choose a random element of an empty slice")
+    }
+
+    #[inline]
+    fn random_or_none(&self) -> Option<&T> {
+        self.random_or_none_with(&mut rand::thread_rng())
+    }
+
+    #[inline]
+    fn random_or_none_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        self.choose(rng)
+    }
+
+    #[inline]
+    fn shuffle(&mut self) {
+        self.shuffle_with(&mut rand::thread_rng());
+    }
+
+    #[inline]
+    fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        SliceRandom::shuffle(self, rng);
+    }
+
+    #[inline]
+    fn shuffled(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.shuffled_with(&mut rand::thread_rng())
+    }
+
+    #[inline]
+    fn shuffled_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut shuffled = self.to_vec();
+        SliceRandom::shuffle(&mut shuffled[..], rng);
+        shuffled
+    }
+}
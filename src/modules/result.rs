@@ -0,0 +1,482 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::Sum;
+
+use crate::{run_catching, CatchingError};
+
+/// Kotlin-style extensions for [`Result`].
+pub trait ResultExt<T, E> {
+    /// Calls the specified function with a reference to the contained value if this is `Ok`, and returns the original
+    /// result.
+    ///
+    /// This is Kotlin's `Result.onSuccess`, and is equivalent to [`also_ok`](crate::ResultScope::also_ok).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// let mut successes = 0;
+    ///
+    /// let value = "42".parse::<i32>().on_success(|_| successes += 1).map(|n| n * 2);
+    ///
+    /// assert_eq!(value, Ok(84));
+    /// assert_eq!(successes, 1);
+    /// ```
+    fn on_success<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> R;
+
+    /// Calls the specified function with a reference to the contained error if this is `Err`, and returns the
+    /// original result.
+    ///
+    /// This is Kotlin's `Result.onFailure`, and is equivalent to [`also_err`](crate::ResultScope::also_err).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let value = "forty-two"
+    ///     .parse::<i32>()
+    ///     .on_success(|n| log.push(format!("parsed {n}")))
+    ///     .on_failure(|e| log.push(format!("failed: {e}")));
+    ///
+    /// assert!(value.is_err());
+    /// assert_eq!(log, ["failed: invalid digit found in string"]);
+    /// ```
+    fn on_failure<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> R;
+
+    /// Maps the contained value using the specified fallible function, converting both a returned error and a panic
+    /// inside the function into the `Err` variant.
+    ///
+    /// An existing error is kept as [`CatchingError::Error`]. This is Kotlin's `Result.mapCatching`, with the caveats
+    /// of [`run_catching`](crate::run_catching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::{CatchingError, ResultExt};
+    ///
+    /// fn checked_ratio(input: &str) -> Result<i32, CatchingError<String>> {
+    ///     input.parse::<i32>().map_err(|e| e.to_string()).map_catching(|n| {
+    ///         if n < 0 {
+    ///             return Err(String::from("negative"));
+    ///         }
+    ///         Ok(100 / n) // panics for 0
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(checked_ratio("4").ok(), Some(25));
+    /// assert_eq!(checked_ratio("-4").unwrap_err().error().as_deref(), Some("negative"));
+    /// assert_eq!(checked_ratio("four").unwrap_err().error().as_deref(), Some("invalid digit found in string"));
+    /// assert!(checked_ratio("0").unwrap_err().is_panic());
+    /// ```
+    fn map_catching<U, F>(self, f: F) -> Result<U, CatchingError<E>>
+    where
+        F: FnOnce(T) -> Result<U, E>;
+
+    /// Maps the contained error to a value using the specified function, producing a result that is always `Ok`.
+    ///
+    /// This is Kotlin's `Result.recover`. The error type of the returned result is [`Infallible`], so the value can be
+    /// extracted with `let Ok(value) = ...`, or further processed as a result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// let Ok(port) = "http".parse::<u16>().recover(|_| 80);
+    ///
+    /// assert_eq!(port, 80);
+    /// ```
+    fn recover<F>(self, f: F) -> Result<T, Infallible>
+    where
+        F: FnOnce(E) -> T;
+
+    /// Maps the contained error using the specified fallible recovery function, converting both a returned error and
+    /// a panic inside the function into the `Err` variant.
+    ///
+    /// This is Kotlin's `Result.recoverCatching`, with the caveats of [`run_catching`](crate::run_catching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// fn parse_with_fallback(input: &str, fallback: &str) -> Option<i32> {
+    ///     input.parse::<i32>().recover_catching(|_| fallback.parse()).ok()
+    /// }
+    ///
+    /// assert_eq!(parse_with_fallback("1", "2"), Some(1));
+    /// assert_eq!(parse_with_fallback("one", "2"), Some(2));
+    /// assert_eq!(parse_with_fallback("one", "two"), None);
+    ///
+    /// let result = "one".parse::<i32>().recover_catching(|_| -> Result<i32, _> { panic!("no fallback") });
+    /// assert!(result.unwrap_err().is_panic());
+    /// ```
+    fn recover_catching<F>(self, f: F) -> Result<T, CatchingError<E>>
+    where
+        F: FnOnce(E) -> Result<T, E>;
+
+    /// Returns the contained value, or the result of calling the specified function with the error if this is `Err`.
+    ///
+    /// This is Kotlin's `Result.getOrElse`, and is equivalent to [`Result::unwrap_or_else`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// let message = "x".parse::<i32>().map(|n| n.to_string()).get_or_else(|e| format!("error: {e}"));
+    ///
+    /// assert_eq!(message, "error: invalid digit found in string");
+    /// ```
+    #[must_use]
+    fn get_or_else<F>(self, f: F) -> T
+    where
+        F: FnOnce(E) -> T;
+
+    /// Returns the contained value, or the default value of `T` if this is `Err`.
+    ///
+    /// This is equivalent to [`Result::unwrap_or_default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// assert_eq!("12".parse::<i32>().get_or_default(), 12);
+    /// assert_eq!("twelve".parse::<i32>().get_or_default(), 0);
+    /// ```
+    #[must_use]
+    fn get_or_default(self) -> T
+    where
+        T: Default;
+
+    /// Collapses both variants into a single value, calling `on_ok` with the contained value or `on_err` with the
+    /// contained error.
+    ///
+    /// This is Kotlin's `Result.fold`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// fn describe(input: &str) -> String {
+    ///     input.parse::<u8>().fold(|n| format!("byte {n:#04x}"), |e| format!("not a byte ({e})"))
+    /// }
+    ///
+    /// assert_eq!(describe("255"), "byte 0xff");
+    /// assert_eq!(describe("256"), "not a byte (number too large to fit in target type)");
+    /// ```
+    #[must_use]
+    fn fold<R, FT, FE>(self, on_ok: FT, on_err: FE) -> R
+    where
+        FT: FnOnce(T) -> R,
+        FE: FnOnce(E) -> R;
+
+    /// Returns the contained value, or panics with the message returned by the specified function followed by the
+    /// error if this is `Err`.
+    ///
+    /// Unlike [`Result::expect`], the message is only built when the result is `Err`, so it can be expensive to
+    /// format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is `Err`, with the returned message and the [`Debug`] representation of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultExt;
+    ///
+    /// let path = "config.toml";
+    /// let size: u64 = "1024".parse().expect_lazy(|| format!("invalid size in {path}"));
+    ///
+    /// assert_eq!(size, 1024);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use kust::ResultExt;
+    ///
+    /// let path = "config.toml";
+    /// let size: u64 = "big".parse().expect_lazy(|| format!("invalid size in {path}")); // panics
+    /// ```
+    #[must_use]
+    fn expect_lazy<F, M>(self, f: F) -> T
+    where
+        E: Debug,
+        F: FnOnce() -> M,
+        M: Display;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[inline]
+    fn on_success<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if let Ok(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    #[inline]
+    fn on_failure<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> R,
+    {
+        if let Err(error) = &self {
+            f(error);
+        }
+        self
+    }
+
+    #[inline]
+    fn map_catching<U, F>(self, f: F) -> Result<U, CatchingError<E>>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        let value = self.map_err(CatchingError::Error)?;
+        run_catching(|| f(value))?.map_err(CatchingError::Error)
+    }
+
+    #[inline]
+    fn recover<F>(self, f: F) -> Result<T, Infallible>
+    where
+        F: FnOnce(E) -> T,
+    {
+        Ok(self.unwrap_or_else(f))
+    }
+
+    #[inline]
+    fn recover_catching<F>(self, f: F) -> Result<T, CatchingError<E>>
+    where
+        F: FnOnce(E) -> Result<T, E>,
+    {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => run_catching(|| f(error))?.map_err(CatchingError::Error),
+        }
+    }
+
+    #[inline]
+    fn get_or_else<F>(self, f: F) -> T
+    where
+        F: FnOnce(E) -> T,
+    {
+        self.unwrap_or_else(f)
+    }
+
+    #[inline]
+    fn get_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.unwrap_or_default()
+    }
+
+    #[inline]
+    fn fold<R, FT, FE>(self, on_ok: FT, on_err: FE) -> R
+    where
+        FT: FnOnce(T) -> R,
+        FE: FnOnce(E) -> R,
+    {
+        match self {
+            Ok(value) => on_ok(value),
+            Err(error) => on_err(error),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_lazy<F, M>(self, f: F) -> T
+    where
+        E: Debug,
+        F: FnOnce() -> M,
+        M: Display,
+    {
+        match self {
+            Ok(value) => value,
+            Err(error) => panic!("{}: {error:?}", f()),
+        }
+    }
+}
+
+/// Extensions for iterators of [`Result`]s.
+pub trait ResultIterExt<T, E>: Iterator<Item = Result<T, E>> {
+    /// Splits the results into the successful values and the errors, in a single pass.
+    ///
+    /// Unlike collecting into a `Result<Vec<T>, E>`, which stops at the first error, this keeps all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultIterExt;
+    ///
+    /// let (numbers, errors) = ["1", "two", "3", "four"].iter().map(|s| s.parse::<i32>()).partition_results();
+    ///
+    /// assert_eq!(numbers, [1, 3]);
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn partition_results(self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Sized,
+    {
+        self.partition_results_into()
+    }
+
+    /// Splits the results into the successful values and the errors, in a single pass, collecting them into custom
+    /// collections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use kust::ResultIterExt;
+    ///
+    /// let (numbers, errors): (BTreeSet<i32>, Vec<_>) =
+    ///     ["3", "1", "x", "3"].iter().map(|s| s.parse::<i32>()).partition_results_into();
+    ///
+    /// assert_eq!(numbers, BTreeSet::from([1, 3]));
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    #[must_use]
+    fn partition_results_into<A, B>(self) -> (A, B)
+    where
+        Self: Sized,
+        A: Default + Extend<T>,
+        B: Default + Extend<E>,
+    {
+        let mut values = A::default();
+        let mut errors = B::default();
+        for result in self {
+            match result {
+                Ok(value) => values.extend(Some(value)),
+                Err(error) => errors.extend(Some(error)),
+            }
+        }
+        (values, errors)
+    }
+
+    /// Collects the successful values into a `Vec`, stopping at the first error, which is returned along with its
+    /// zero-based index in the iterator.
+    ///
+    /// As collection stops at the first error, its index is also the number of values already collected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    /// use kust::ResultIterExt;
+    ///
+    /// let input = "10\n20\nthirty\n40";
+    ///
+    /// let error = input.lines().map(|l| l.parse::<i32>()).collect_results_indexed().unwrap_err();
+    /// assert_eq!(error.index, 2);
+    /// assert_eq!(error.to_string(), "error at index 2");
+    /// assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+    ///
+    /// let numbers = "1\n2".lines().map(|l| l.parse::<i32>()).collect_results_indexed();
+    /// assert_eq!(numbers.ok(), Some(vec![1, 2]));
+    /// ```
+    fn collect_results_indexed(self) -> Result<Vec<T>, IndexedError<E>>
+    where
+        Self: Sized,
+    {
+        let mut values = Vec::with_capacity(self.size_hint().0);
+        for (index, result) in self.enumerate() {
+            match result {
+                Ok(value) => values.push(value),
+                Err(error) => return Err(IndexedError { index, error }),
+            }
+        }
+        Ok(values)
+    }
+
+    /// Folds the successful values into an accumulator, stopping at the first error, which is returned instead.
+    ///
+    /// No intermediate collection is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultIterExt;
+    ///
+    /// let product = ["2", "3", "4"].iter().map(|s| s.parse::<i64>()).fold_results(1, |acc, n| acc * n);
+    /// assert_eq!(product, Ok(24));
+    ///
+    /// let product = ["2", "x", "4"].iter().map(|s| s.parse::<i64>()).fold_results(1, |acc, n| acc * n);
+    /// assert!(product.is_err());
+    /// ```
+    fn fold_results<B, F>(self, init: B, mut f: F) -> Result<B, E>
+    where
+        Self: Sized,
+        F: FnMut(B, T) -> B,
+    {
+        let mut accumulator = init;
+        for result in self {
+            accumulator = f(accumulator, result?);
+        }
+        Ok(accumulator)
+    }
+
+    /// Sums the successful values, stopping at the first error, which is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultIterExt;
+    ///
+    /// let total: Result<u32, _> = "4 8 15 16 23 42".split(' ').map(str::parse::<u32>).sum_results();
+    /// assert_eq!(total, Ok(108));
+    ///
+    /// let total: Result<u32, _> = "4 8 fifteen".split(' ').map(str::parse::<u32>).sum_results();
+    /// assert!(total.is_err());
+    /// ```
+    #[inline]
+    fn sum_results<S>(self) -> Result<S, E>
+    where
+        Self: Sized,
+        S: Sum<T>,
+    {
+        self.sum()
+    }
+}
+
+impl<I, T, E> ResultIterExt<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+/// An error annotated with the zero-based index of the element that caused it, returned by
+/// [`collect_results_indexed`](ResultIterExt::collect_results_indexed).
+///
+/// Only the index is displayed; the error of the element is the [source](Error::source), so that error reporters
+/// which print the whole chain don't print it twice.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndexedError<E> {
+    /// The zero-based index of the failing element.
+    pub index: usize,
+    /// The error of the failing element.
+    pub error: E,
+}
+
+impl<E> Display for IndexedError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error at index {}", self.index)
+    }
+}
+
+impl<E: Error + 'static> Error for IndexedError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
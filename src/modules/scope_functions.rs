@@ -1,3 +1,9 @@
+use std::fmt::Debug;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+
+use crate::PanicError;
+
 pub trait ScopeFunctions {
     /// Calls the specified function with this value as an argument and returns the result of the function.
     ///
@@ -32,6 +38,7 @@ pub trait ScopeFunctions {
     /// Average and median: (6, 11)
     /// ")
     /// ```
+    #[inline]
     fn using<F, R>(self, f: F) -> R
     where
         Self: Sized,
@@ -40,6 +47,35 @@ pub trait ScopeFunctions {
         f(self)
     }
 
+    /// Calls the specified function with `self` as an argument and returns the result of the function.
+    ///
+    /// This is Kotlin's `T.run`. As Rust doesn't distinguish between `this` and `it` receivers, it differs from
+    /// [`using`](ScopeFunctions::using) only in intent: `run` takes ownership of the receiver to compute a result,
+    /// without borrowing it. See also the standalone [`run`](crate::run) function, which takes no receiver at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// let words = vec!["apple", "banana", "cherry"];
+    ///
+    /// let description = words.run(|mut w| {
+    ///     w.reverse();
+    ///     w.join(", ")
+    /// });
+    ///
+    /// assert_eq!(description, "cherry, banana, apple");
+    /// ```
+    #[inline]
+    fn run<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> R,
+    {
+        f(self)
+    }
+
     /// Calls the specified function with an immutable reference to `self` and returns `self`.
     ///
     /// Use `also` when you want to perform a side effect using a value before returning it.
@@ -64,6 +100,8 @@ pub trait ScopeFunctions {
     /// The sum is 20
     /// ")
     /// ```
+    #[inline]
+    #[must_use]
     fn also<F, R>(self, f: F) -> Self
     where
         Self: Sized,
@@ -73,6 +111,139 @@ pub trait ScopeFunctions {
         self
     }
 
+    /// Calls the specified function with an immutable reference to the pinned value and returns the same pinned
+    /// reference.
+    ///
+    /// This is the pinned counterpart of [`also`](ScopeFunctions::also), for values that can't be moved, such as
+    /// futures and self-referential types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::pin::pin;
+    /// use kust::ScopeFunctions;
+    ///
+    /// let mut sizes = Vec::new();
+    ///
+    /// let future = pin!(async { 42 });
+    /// let _future = future.also_pinned(|f| sizes.push(std::mem::size_of_val(f)));
+    ///
+    /// assert_eq!(sizes.len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn also_pinned<F, R>(self: Pin<&mut Self>, f: F) -> Pin<&mut Self>
+    where
+        F: FnOnce(&Self) -> R,
+    {
+        f(self.as_ref().get_ref());
+        self
+    }
+
+    /// Calls the specified function with a reborrow of the pinned value and returns the same pinned reference.
+    ///
+    /// This is the pinned counterpart of [`apply`](ScopeFunctions::apply), allowing pinned values, such as futures
+    /// and streams, to be configured fluently before polling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::marker::PhantomPinned;
+    /// use std::pin::{pin, Pin};
+    /// use kust::ScopeFunctions;
+    ///
+    /// struct Timer {
+    ///     millis: u64,
+    ///     _pin: PhantomPinned,
+    /// }
+    ///
+    /// impl Timer {
+    ///     fn set_millis(self: Pin<&mut Self>, millis: u64) {
+    ///         // SAFETY: `millis` is not structurally pinned
+    ///         unsafe { self.get_unchecked_mut().millis = millis }
+    ///     }
+    /// }
+    ///
+    /// let timer = pin!(Timer { millis: 0, _pin: PhantomPinned });
+    /// let timer = timer.apply_pinned(|t| t.set_millis(250));
+    ///
+    /// assert_eq!(timer.millis, 250);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn apply_pinned<F, R>(mut self: Pin<&mut Self>, f: F) -> Pin<&mut Self>
+    where
+        F: FnOnce(Pin<&mut Self>) -> R,
+    {
+        f(self.as_mut());
+        self
+    }
+
+    /// Prints `self` to stderr, along with the caller's location, and returns `self`.
+    ///
+    /// This works like the [`dbg!`] macro, but can be inserted in the middle of a method chain. With the
+    /// `no-dbg-in-release` feature enabled, it does nothing in release builds (without debug assertions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// let total: i32 = [1, 2, 3]
+    ///     .iter()
+    ///     .map(|n| n * 10)
+    ///     .collect::<Vec<_>>()
+    ///     .also_dbg() // prints: [src/main.rs:7:6] [10, 20, 30]
+    ///     .iter()
+    ///     .sum();
+    ///
+    /// assert_eq!(total, 60);
+    /// ```
+    #[track_caller]
+    #[inline]
+    #[must_use]
+    fn also_dbg(self) -> Self
+    where
+        Self: Sized + Debug,
+    {
+        #[cfg(not(all(feature = "no-dbg-in-release", not(debug_assertions))))]
+        {
+            let location = std::panic::Location::caller();
+            eprintln!("[{}:{}:{}] {:#?}", location.file(), location.line(), location.column(), self);
+        }
+        self
+    }
+
+    /// Prints `self` to stderr with the given label, along with the caller's location, and returns `self`.
+    ///
+    /// See [`also_dbg`](ScopeFunctions::also_dbg).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// let name = " Mike ".trim().also_dbg_labeled("trimmed"); // prints: [src/main.rs:3:28] trimmed = "Mike"
+    ///
+    /// assert_eq!(name, "Mike");
+    /// ```
+    #[track_caller]
+    #[inline]
+    #[must_use]
+    fn also_dbg_labeled(self, label: &str) -> Self
+    where
+        Self: Sized + Debug,
+    {
+        #[cfg(not(all(feature = "no-dbg-in-release", not(debug_assertions))))]
+        {
+            let location = std::panic::Location::caller();
+            eprintln!("[{}:{}:{}] {label} = {:#?}", location.file(), location.line(), location.column(), self);
+        }
+        #[cfg(all(feature = "no-dbg-in-release", not(debug_assertions)))]
+        let _ = label;
+        self
+    }
+
     /// Calls the specified function with a mutable reference to `self` and returns `self`.
     ///
     /// Use `apply` when you want to modify an object before returning it.
@@ -106,6 +277,8 @@ pub trait ScopeFunctions {
     ///
     /// assert_eq!(people, [String::from("Name: Mike, age: 34"), String::from("Name: Linda, age: 25")]);
     /// ```
+    #[inline]
+    #[must_use]
     fn apply<F, R>(mut self, f: F) -> Self
     where
         Self: Sized,
@@ -114,6 +287,795 @@ pub trait ScopeFunctions {
         f(&mut self);
         self
     }
+
+    /// Calls the specified function with an immutable reference to `self` if the condition holds, and returns `self`.
+    ///
+    /// The condition is either a `bool` or a predicate on `&Self`. See [`also`](ScopeFunctions::also).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// let mut warnings = Vec::new();
+    ///
+    /// let scores: Vec<i32> = [95, -3, 40]
+    ///     .into_iter()
+    ///     .map(|s| s.also_if(|s: &i32| *s < 0, |s| warnings.push(format!("negative score: {s}"))))
+    ///     .collect();
+    ///
+    /// assert_eq!(scores, [95, -3, 40]);
+    /// assert_eq!(warnings, ["negative score: -3"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn also_if<C, F, R>(self, condition: C, f: F) -> Self
+    where
+        Self: Sized,
+        C: Condition<Self>,
+        F: FnOnce(&Self) -> R,
+    {
+        if condition.test(&self) {
+            f(&self);
+        }
+        self
+    }
+
+    /// Calls the specified function with a mutable reference to `self` if the condition holds, and returns `self`.
+    ///
+    /// The condition is either a `bool` or a predicate on `&Self`. See [`apply`](ScopeFunctions::apply).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// #[derive(Default)]
+    /// struct Request {
+    ///     url: String,
+    ///     headers: Vec<(String, String)>,
+    /// }
+    ///
+    /// fn build(url: &str, token: Option<&str>) -> Request {
+    ///     Request::default()
+    ///         .apply(|r| r.url = String::from(url))
+    ///         .apply_if(token.is_some(), |r| r.headers.push((String::from("Authorization"), token.unwrap().into())))
+    ///         .apply_if(|r: &Request| r.url.starts_with("https"), |r| r.headers.push(("Secure".into(), "1".into())))
+    /// }
+    ///
+    /// assert_eq!(build("http://example.com", None).headers.len(), 0);
+    /// assert_eq!(build("https://example.com", Some("abc")).headers.len(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn apply_if<C, F, R>(mut self, condition: C, f: F) -> Self
+    where
+        Self: Sized,
+        C: Condition<Self>,
+        F: FnOnce(&mut Self) -> R,
+    {
+        if condition.test(&self) {
+            f(&mut self);
+        }
+        self
+    }
+
+    /// Calls the specified function with this value as an argument and returns its result, or the caught panic if it
+    /// panics.
+    ///
+    /// This is the scope function counterpart of [`run_catching`](crate::run_catching), which describes the caveats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// let values = vec![1, 2, 3];
+    ///
+    /// assert_eq!(values.clone().catching(|v| v[1]).ok(), Some(2));
+    /// assert!(values.catching(|v| v[10]).is_err());
+    /// ```
+    #[inline]
+    fn catching<F, R>(self, f: F) -> Result<R, PanicError>
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> R,
+    {
+        std::panic::catch_unwind(AssertUnwindSafe(|| f(self))).map_err(PanicError::new)
+    }
+
+    /// Calls the specified fallible function with this value as an argument and returns the result of the function.
+    ///
+    /// This is the fallible counterpart of [`using`](ScopeFunctions::using). It is equivalent to `using`, but makes
+    /// it explicit that the function returns a [`Result`], allowing the use of `?` inside the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use kust::ScopeFunctions;
+    ///
+    /// fn parse_point(input: &str) -> Result<(i32, i32), ParseIntError> {
+    ///     input.split_once(',').unwrap_or((input, "0")).try_using(|(x, y)| Ok((x.parse()?, y.parse()?)))
+    /// }
+    ///
+    /// assert_eq!(parse_point("3,4"), Ok((3, 4)));
+    /// assert_eq!(parse_point("7"), Ok((7, 0)));
+    /// assert!(parse_point("3,four").is_err());
+    /// ```
+    #[inline]
+    fn try_using<F, R, E>(self, f: F) -> Result<R, E>
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> Result<R, E>,
+    {
+        f(self)
+    }
+
+    /// Calls the specified fallible function with an immutable reference to `self` and returns `self` if it succeeds,
+    /// or the error otherwise.
+    ///
+    /// This is the fallible counterpart of [`also`](ScopeFunctions::also).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// fn validate(name: String) -> Result<String, String> {
+    ///     name.try_also(|n| if n.is_empty() { Err(String::from("name is empty")) } else { Ok(()) })
+    /// }
+    ///
+    /// assert_eq!(validate(String::from("Mike")), Ok(String::from("Mike")));
+    /// assert_eq!(validate(String::new()), Err(String::from("name is empty")));
+    /// ```
+    #[inline]
+    fn try_also<F, R, E>(self, f: F) -> Result<Self, E>
+    where
+        Self: Sized,
+        F: FnOnce(&Self) -> Result<R, E>,
+    {
+        f(&self)?;
+        Ok(self)
+    }
+
+    /// Calls the specified fallible function with a mutable reference to `self` and returns `self` if it succeeds,
+    /// or the error otherwise.
+    ///
+    /// This is the fallible counterpart of [`apply`](ScopeFunctions::apply), allowing the use of `?` inside
+    /// configuration closures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    /// use kust::ScopeFunctions;
+    ///
+    /// #[derive(Debug, Default, PartialEq)]
+    /// struct Config {
+    ///     width: u32,
+    ///     height: u32,
+    /// }
+    ///
+    /// fn parse_config(width: &str, height: &str) -> Result<Config, ParseIntError> {
+    ///     Config::default().try_apply(|c| {
+    ///         c.width = width.parse()?;
+    ///         c.height = height.parse()?;
+    ///         Ok(())
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(parse_config("800", "600"), Ok(Config { width: 800, height: 600 }));
+    /// assert!(parse_config("800", "tall").is_err());
+    /// ```
+    #[inline]
+    fn try_apply<F, R, E>(mut self, f: F) -> Result<Self, E>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> Result<R, E>,
+    {
+        f(&mut self)?;
+        Ok(self)
+    }
+
+    /// Feeds this value through the specified function the given number of times and returns the final result.
+    ///
+    /// As the function is called repeatedly, it must be [`FnMut`]. Calling this with `times == 0` returns `self`
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// // Collatz sequence, 5 steps from 27
+    /// let value = 27u64.repeat_using(5, |n| if n % 2 == 0 { n / 2 } else { 3 * n + 1 });
+    ///
+    /// assert_eq!(value, 31);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn repeat_using<F>(self, times: usize, mut f: F) -> Self
+    where
+        Self: Sized,
+        F: FnMut(Self) -> Self,
+    {
+        let mut value = self;
+        for _ in 0..times {
+            value = f(value);
+        }
+        value
+    }
+
+    /// Calls the specified function with a mutable reference to `self` the given number of times and returns `self`.
+    ///
+    /// This is the repeated counterpart of [`apply`](ScopeFunctions::apply). Calling this with `times == 0` returns
+    /// `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// let line = String::from("=").repeat_apply(3, |s| s.push_str("-="));
+    ///
+    /// assert_eq!(line, "=-=-=-=");
+    /// ```
+    #[inline]
+    #[must_use]
+    fn repeat_apply<F, R>(mut self, times: usize, mut f: F) -> Self
+    where
+        Self: Sized,
+        F: FnMut(&mut Self) -> R,
+    {
+        for _ in 0..times {
+            f(&mut self);
+        }
+        self
+    }
+
+    /// Returns `Some(self)` if it satisfies the given predicate, or `None` if it doesn't.
+    ///
+    /// Use `take_if` to conditionally adopt a value, continuing the chain with [`Option`] methods.
+    ///
+    /// Note that [`Option`] has an inherent method with the same name, which takes precedence when
+    /// the receiver is an `Option`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// fn parse_port(input: &str) -> u16 {
+    ///     input
+    ///         .parse::<u16>()
+    ///         .unwrap_or(0)
+    ///         .take_if(|port| *port >= 1024)
+    ///         .unwrap_or(8080)
+    /// }
+    ///
+    /// assert_eq!(parse_port("3000"), 3000);
+    /// assert_eq!(parse_port("80"), 8080);
+    /// assert_eq!(parse_port("not a port"), 8080);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn take_if<F>(self, predicate: F) -> Option<Self>
+    where
+        Self: Sized,
+        F: FnOnce(&Self) -> bool,
+    {
+        if predicate(&self) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `Some(self)` if it doesn't satisfy the given predicate, or `None` if it does.
+    ///
+    /// This is the opposite of [`take_if`](ScopeFunctions::take_if).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions;
+    ///
+    /// let names = ["Mike", "", "Linda"];
+    /// let greetings: Vec<_> = names
+    ///     .iter()
+    ///     .map(|name| name.take_unless(|n| n.is_empty()).map_or(String::from("Hi!"), |n| format!("Hi, {n}!")))
+    ///     .collect();
+    ///
+    /// assert_eq!(greetings, ["Hi, Mike!", "Hi!", "Hi, Linda!"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn take_unless<F>(self, predicate: F) -> Option<Self>
+    where
+        Self: Sized,
+        F: FnOnce(&Self) -> bool,
+    {
+        if predicate(&self) {
+            None
+        } else {
+            Some(self)
+        }
+    }
 }
 
 impl<T> ScopeFunctions for T {}
+
+/// Scope functions operating on references, available for unsized types such as `str`, `[T]` and trait objects.
+///
+/// See [`ScopeFunctions`] for the by-value variants.
+pub trait ScopeFunctionsRef {
+    /// Calls the specified function with an immutable reference to `self` and returns the result of the function.
+    ///
+    /// This is the by-reference counterpart of [`using`](ScopeFunctions::using).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctionsRef;
+    ///
+    /// let readings: &[f64] = &[2.5, 3.0, 3.5];
+    /// let average = readings.using_ref(|r| r.iter().sum::<f64>() / r.len() as f64);
+    ///
+    /// assert_eq!(average, 3.0);
+    /// ```
+    #[inline]
+    fn using_ref<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&Self) -> R,
+    {
+        f(self)
+    }
+
+    /// Calls the specified function with an immutable reference to `self` and returns the same reference.
+    ///
+    /// This is the by-reference counterpart of [`also`](ScopeFunctions::also).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Display;
+    /// use kust::ScopeFunctionsRef;
+    ///
+    /// let mut output = Vec::new();
+    ///
+    /// let value: &dyn Display = &42;
+    /// let shown = value.also_ref(|v| output.push(v.to_string())).to_string();
+    ///
+    /// assert_eq!(shown, "42");
+    /// assert_eq!(output, ["42"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn also_ref<F, R>(&self, f: F) -> &Self
+    where
+        F: FnOnce(&Self) -> R,
+    {
+        f(self);
+        self
+    }
+
+    /// Calls the specified function with a mutable reference to `self` and returns the same reference.
+    ///
+    /// This is the by-reference counterpart of [`apply`](ScopeFunctions::apply).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctionsRef;
+    ///
+    /// let mut numbers = [5, 3, 8, 1];
+    /// let smallest = numbers[..].apply_mut(|n| n.sort())[0];
+    ///
+    /// assert_eq!(smallest, 1);
+    /// assert_eq!(numbers, [1, 3, 5, 8]);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn apply_mut<F, R>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        f(self);
+        self
+    }
+}
+
+impl<T: ?Sized> ScopeFunctionsRef for T {}
+
+/// Scope functions for 2-tuples, destructuring the tuple directly into the closure parameters.
+///
+/// See [`ScopeFunctions`] for the general variants.
+pub trait ScopeFunctions2<A, B> {
+    /// Calls the specified function with the elements of this tuple as arguments and returns the result of the
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions2;
+    ///
+    /// let area = "3x4".split_once('x').unwrap().using2(|w, h| w.parse::<u32>().unwrap() * h.parse::<u32>().unwrap());
+    ///
+    /// assert_eq!(area, 12);
+    /// ```
+    fn using2<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(A, B) -> R;
+
+    /// Calls the specified function with immutable references to the elements of this tuple and returns the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions2;
+    ///
+    /// let mut log = Vec::new();
+    /// let (q, r) = (17 / 5, 17 % 5).also2(|q, r| log.push(format!("17 = 5 * {q} + {r}")));
+    ///
+    /// assert_eq!((q, r), (3, 2));
+    /// assert_eq!(log, ["17 = 5 * 3 + 2"]);
+    /// ```
+    #[must_use]
+    fn also2<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&A, &B) -> R;
+
+    /// Calls the specified function with mutable references to the elements of this tuple and returns the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions2;
+    ///
+    /// let (min, max) = (9, 4).apply2(|a, b| if a > b { std::mem::swap(a, b) });
+    ///
+    /// assert_eq!((min, max), (4, 9));
+    /// ```
+    #[must_use]
+    fn apply2<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut A, &mut B) -> R;
+}
+
+impl<A, B> ScopeFunctions2<A, B> for (A, B) {
+    #[inline]
+    fn using2<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(A, B) -> R,
+    {
+        f(self.0, self.1)
+    }
+
+    #[inline]
+    fn also2<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&A, &B) -> R,
+    {
+        f(&self.0, &self.1);
+        self
+    }
+
+    #[inline]
+    fn apply2<F, R>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut A, &mut B) -> R,
+    {
+        f(&mut self.0, &mut self.1);
+        self
+    }
+}
+
+/// Scope functions for 3-tuples, destructuring the tuple directly into the closure parameters.
+///
+/// See [`ScopeFunctions`] for the general variants.
+pub trait ScopeFunctions3<A, B, C> {
+    /// Calls the specified function with the elements of this tuple as arguments and returns the result of the
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions3;
+    ///
+    /// let volume = (2, 3, 4).using3(|w, h, d| w * h * d);
+    ///
+    /// assert_eq!(volume, 24);
+    /// ```
+    fn using3<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(A, B, C) -> R;
+
+    /// Calls the specified function with immutable references to the elements of this tuple and returns the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions3;
+    ///
+    /// let mut log = Vec::new();
+    /// let rgb = (255, 128, 0).also3(|r, g, b| log.push(format!("#{r:02x}{g:02x}{b:02x}")));
+    ///
+    /// assert_eq!(rgb, (255, 128, 0));
+    /// assert_eq!(log, ["#ff8000"]);
+    /// ```
+    #[must_use]
+    fn also3<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&A, &B, &C) -> R;
+
+    /// Calls the specified function with mutable references to the elements of this tuple and returns the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ScopeFunctions3;
+    ///
+    /// let scaled = (1, 2, 3).apply3(|x, y, z| {
+    ///     *x *= 10;
+    ///     *y *= 10;
+    ///     *z *= 10;
+    /// });
+    ///
+    /// assert_eq!(scaled, (10, 20, 30));
+    /// ```
+    #[must_use]
+    fn apply3<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut A, &mut B, &mut C) -> R;
+}
+
+impl<A, B, C> ScopeFunctions3<A, B, C> for (A, B, C) {
+    #[inline]
+    fn using3<F, R>(self, f: F) -> R
+    where
+        F: FnOnce(A, B, C) -> R,
+    {
+        f(self.0, self.1, self.2)
+    }
+
+    #[inline]
+    fn also3<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&A, &B, &C) -> R,
+    {
+        f(&self.0, &self.1, &self.2);
+        self
+    }
+
+    #[inline]
+    fn apply3<F, R>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut A, &mut B, &mut C) -> R,
+    {
+        f(&mut self.0, &mut self.1, &mut self.2);
+        self
+    }
+}
+
+/// Scope functions for [`Result`] receivers, operating on the contained value of the matching variant.
+pub trait ResultScope<T, E> {
+    /// Calls the specified function with an immutable reference to the contained value if this is `Ok`, and returns
+    /// the original result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultScope;
+    ///
+    /// let mut parsed = Vec::new();
+    ///
+    /// let results: Vec<_> = ["1", "two", "3"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<i32>().also_ok(|n| parsed.push(*n)))
+    ///     .collect();
+    ///
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(parsed, [1, 3]);
+    /// ```
+    #[must_use]
+    fn also_ok<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> R;
+
+    /// Calls the specified function with an immutable reference to the contained error if this is `Err`, and returns
+    /// the original result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultScope;
+    ///
+    /// let mut errors = Vec::new();
+    ///
+    /// let total: i32 = ["1", "two", "3"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<i32>().also_err(|e| errors.push(format!("{s}: {e}"))))
+    ///     .filter_map(Result::ok)
+    ///     .sum();
+    ///
+    /// assert_eq!(total, 4);
+    /// assert_eq!(errors, ["two: invalid digit found in string"]);
+    /// ```
+    #[must_use]
+    fn also_err<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> R;
+
+    /// Calls the specified function with a mutable reference to the contained value if this is `Ok`, and returns
+    /// the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::ResultScope;
+    ///
+    /// let words: Result<Vec<&str>, String> = Ok(vec!["b", "c", "a"]);
+    ///
+    /// assert_eq!(words.apply_ok(|w| w.sort()), Ok(vec!["a", "b", "c"]));
+    /// ```
+    #[must_use]
+    fn apply_ok<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T, E> ResultScope<T, E> for Result<T, E> {
+    #[inline]
+    fn also_ok<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if let Ok(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    #[inline]
+    fn also_err<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> R,
+    {
+        if let Err(error) = &self {
+            f(error);
+        }
+        self
+    }
+
+    #[inline]
+    fn apply_ok<F, R>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        if let Ok(value) = &mut self {
+            f(value);
+        }
+        self
+    }
+}
+
+/// Scope functions for [`Option`] receivers, mirroring Kotlin's `?.also` and `?.apply` on nullable values.
+pub trait OptionScope<T> {
+    /// Calls the specified function with an immutable reference to the contained value if this is `Some`, and
+    /// returns the original option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::OptionScope;
+    ///
+    /// let mut log = Vec::new();
+    /// let users = ["mike", "linda"];
+    ///
+    /// let found = users.iter().find(|u| u.starts_with('l')).also_some(|u| log.push(format!("found {u}")));
+    ///
+    /// assert_eq!(found, Some(&"linda"));
+    /// assert_eq!(log, ["found linda"]);
+    /// ```
+    #[must_use]
+    fn also_some<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> R;
+
+    /// Calls the specified function if this is `None`, and returns the original option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::OptionScope;
+    ///
+    /// let mut misses = 0;
+    /// let found = ["mike", "linda"].iter().find(|u| u.starts_with('x')).also_none(|| misses += 1);
+    ///
+    /// assert_eq!(found, None);
+    /// assert_eq!(misses, 1);
+    /// ```
+    #[must_use]
+    fn also_none<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce() -> R;
+
+    /// Calls the specified function with a mutable reference to the contained value if this is `Some`, and returns
+    /// the option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::OptionScope;
+    ///
+    /// let tags = Some(vec!["rust"]).apply_some(|t| t.push("kotlin"));
+    ///
+    /// assert_eq!(tags, Some(vec!["rust", "kotlin"]));
+    /// assert_eq!(None::<Vec<&str>>.apply_some(|t| t.push("kotlin")), None);
+    /// ```
+    #[must_use]
+    fn apply_some<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T> OptionScope<T> for Option<T> {
+    #[inline]
+    fn also_some<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if let Some(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    #[inline]
+    fn also_none<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce() -> R,
+    {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+
+    #[inline]
+    fn apply_some<F, R>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        if let Some(value) = &mut self {
+            f(value);
+        }
+        self
+    }
+}
+
+/// A condition for the conditional scope functions, such as [`apply_if`](ScopeFunctions::apply_if).
+///
+/// Implemented for `bool` and for predicates on `&T`.
+pub trait Condition<T: ?Sized> {
+    /// Tests whether the condition holds for the given value.
+    fn test(self, value: &T) -> bool;
+}
+
+impl<T: ?Sized> Condition<T> for bool {
+    #[inline]
+    fn test(self, _value: &T) -> bool {
+        self
+    }
+}
+
+impl<T: ?Sized, F> Condition<T> for F
+where
+    F: FnOnce(&T) -> bool,
+{
+    #[inline]
+    fn test(self, value: &T) -> bool {
+        self(value)
+    }
+}
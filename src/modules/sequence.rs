@@ -0,0 +1,205 @@
+use std::cell::Cell;
+use std::iter::{successors, Successors};
+
+/// A lazy sequence of values, Kotlin's `Sequence`.
+///
+/// A sequence is an [`Iterator`], and can be iterated by reference as many times as needed if the underlying iterator
+/// is [`Clone`], each time from the start - just like a Kotlin sequence. Use
+/// [`constrain_once`](Sequence::constrain_once) to forbid iterating it more than once. Sequences are created with
+/// [`Sequence::new`], [`generate_sequence`] or the [`sequence!`](crate::sequence) macro.
+///
+/// # Examples
+///
+/// ```
+/// use kust::Sequence;
+///
+/// let evens = Sequence::new((1..=6).filter(|n| n % 2 == 0));
+///
+/// assert_eq!((&evens).into_iter().sum::<i32>(), 12);
+/// assert_eq!((&evens).into_iter().max(), Some(6));
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "sequences are lazy and do nothing unless consumed"]
+pub struct Sequence<I> {
+    iter: I,
+}
+
+impl<I: Iterator> Sequence<I> {
+    /// Creates a sequence of the elements of the specified iterable.
+    ///
+    /// This is Kotlin's `asSequence`.
+    #[inline]
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Sequence { iter: iter.into_iter() }
+    }
+
+    /// Returns a wrapper around this sequence that can be iterated only once, panicking on any later attempt.
+    ///
+    /// This is Kotlin's `constrainOnce`, useful for sequences backed by one-shot resources, such as a stream being
+    /// read.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use kust::Sequence;
+    ///
+    /// let lines = Sequence::new(["first", "second"]).constrain_once();
+    ///
+    /// assert_eq!((&lines).into_iter().count(), 2);
+    /// (&lines).into_iter().count(); // panics
+    /// ```
+    #[inline]
+    pub fn constrain_once(self) -> ConstrainedOnce<I> {
+        ConstrainedOnce { iter: Cell::new(Some(self.iter)) }
+    }
+}
+
+impl<I: Iterator> Iterator for Sequence<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Iterator + Clone> IntoIterator for &Sequence<I> {
+    type Item = I::Item;
+    type IntoIter = I;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.clone()
+    }
+}
+
+/// A sequence that can be iterated only once, created by [`Sequence::constrain_once`].
+///
+/// Iterating it by reference a second time, or by value after having iterated it by reference, panics.
+#[must_use = "sequences are lazy and do nothing unless consumed"]
+pub struct ConstrainedOnce<I> {
+    iter: Cell<Option<I>>,
+}
+
+impl<I> ConstrainedOnce<I> {
+    #[track_caller]
+    fn take(&self) -> I {
+        self.iter.take().expect("the sequence can be consumed only once")
+    }
+}
+
+impl<I: Iterator> IntoIterator for ConstrainedOnce<I> {
+    type Item = I::Item;
+    type IntoIter = I;
+
+    #[inline]
+    #[track_caller]
+    fn into_iter(self) -> Self::IntoIter {
+        self.take()
+    }
+}
+
+impl<I: Iterator> IntoIterator for &ConstrainedOnce<I> {
+    type Item = I::Item;
+    type IntoIter = I;
+
+    #[inline]
+    #[track_caller]
+    fn into_iter(self) -> Self::IntoIter {
+        self.take()
+    }
+}
+
+/// Returns a sequence starting with `seed`, where each next value is computed by the specified function from the
+/// previous one, ending when the function returns [`None`].
+///
+/// This is Kotlin's `generateSequence`, and is built on [`std::iter::successors`].
+///
+/// # Examples
+///
+/// ```
+/// use kust::generate_sequence;
+///
+/// let collatz: Vec<u32> = generate_sequence(6, |&n| match n {
+///     1 => None,
+///     n if n % 2 == 0 => Some(n / 2),
+///     n => Some(3 * n + 1),
+/// })
+/// .collect();
+///
+/// assert_eq!(collatz, [6, 3, 10, 5, 16, 8, 4, 2, 1]);
+/// ```
+#[inline]
+pub fn generate_sequence<T, F>(seed: T, next: F) -> Sequence<Successors<T, F>>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    Sequence { iter: successors(Some(seed), next) }
+}
+
+/// Builds a lazy [`Sequence`] from `yield` and `yield_all` statements, separated by semicolons.
+///
+/// `yield value` adds a single value, and `yield_all values` adds all values of an iterable. Each expression is only
+/// evaluated when the sequence reaches it, like in Kotlin's `sequence { ... }` builder. The expressions are captured by
+/// `move` closures, so the sequence can be returned from a function; clone what needs to be shared between several
+/// statements.
+///
+/// # Examples
+///
+/// ```
+/// use kust::sequence;
+///
+/// let header = "id,name".to_string();
+/// let rows = vec!["1,Mike", "2,Linda"];
+///
+/// let csv: Vec<String> = sequence! {
+///     yield header;
+///     yield_all rows.into_iter().map(String::from);
+///     yield "3,Anna".to_string();
+/// }
+/// .collect();
+///
+/// assert_eq!(csv, ["id,name", "1,Mike", "2,Linda", "3,Anna"]);
+/// ```
+///
+/// Nothing is evaluated before it is needed:
+///
+/// ```
+/// use kust::sequence;
+///
+/// let mut numbers = sequence! {
+///     yield 1;
+///     yield panic!("never evaluated")
+/// };
+///
+/// assert_eq!(numbers.next(), Some(1));
+/// ```
+#[macro_export]
+macro_rules! sequence {
+    (@chain) => {
+        ::core::iter::empty()
+    };
+    (@chain yield $value:expr $(; $($rest:tt)*)?) => {
+        ::core::iter::Iterator::chain(
+            ::core::iter::once_with(move || $value),
+            $crate::sequence!(@chain $($($rest)*)?),
+        )
+    };
+    (@chain yield_all $values:expr $(; $($rest:tt)*)?) => {
+        ::core::iter::Iterator::chain(
+            ::core::iter::Iterator::flatten(::core::iter::once_with(move || $values)),
+            $crate::sequence!(@chain $($($rest)*)?),
+        )
+    };
+    ($($body:tt)*) => {
+        $crate::Sequence::new($crate::sequence!(@chain $($body)*))
+    };
+}
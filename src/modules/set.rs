@@ -0,0 +1,232 @@
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+/// Set algebra returning owned sets, implemented for [`HashSet`] and [`BTreeSet`].
+///
+/// The std operations such as [`HashSet::union`] borrow both sets and return lazy iterators, which then need
+/// `.cloned().collect()`. These functions consume the receiver instead and reuse it for the result, so that they
+/// compose in expressions. The `_all` variants accept any iterator instead of a set of the same type.
+pub trait SetExt<T>: Sized {
+    /// Returns this set with the elements of the other set added.
+    ///
+    /// Elements present in both sets are kept from this set. This is Kotlin's `union`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use kust::SetExt;
+    ///
+    /// let admins = HashSet::from(["mike"]);
+    /// let editors = HashSet::from(["linda", "mike"]);
+    ///
+    /// assert_eq!(admins.union_with(editors), HashSet::from(["mike", "linda"]));
+    /// ```
+    #[must_use]
+    fn union_with(self, other: Self) -> Self;
+
+    /// Returns this set with the elements of the specified iterator added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use kust::SetExt;
+    ///
+    /// let tags = BTreeSet::from(["rust"]).union_with_all("kotlin,rust".split(','));
+    /// assert_eq!(tags, BTreeSet::from(["kotlin", "rust"]));
+    /// ```
+    #[must_use]
+    fn union_with_all<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>;
+
+    /// Returns this set with only the elements also present in the other set.
+    ///
+    /// This is Kotlin's `intersect`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use kust::SetExt;
+    ///
+    /// let wanted = HashSet::from(["serde", "rand", "tokio"]);
+    /// let available = HashSet::from(["rand", "serde", "log"]);
+    ///
+    /// assert_eq!(wanted.intersect_with(&available), HashSet::from(["serde", "rand"]));
+    /// ```
+    #[must_use]
+    fn intersect_with(self, other: &Self) -> Self;
+
+    /// Returns this set with only the elements also returned by the specified iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use kust::SetExt;
+    ///
+    /// let primes = BTreeSet::from([2, 3, 5, 7, 11]);
+    /// assert_eq!(primes.intersect_with_all(1..=6), BTreeSet::from([2, 3, 5]));
+    /// ```
+    #[must_use]
+    fn intersect_with_all<I>(self, iter: I) -> Self
+    where
+        Self: FromIterator<T>,
+        I: IntoIterator<Item = T>;
+
+    /// Returns this set without the elements present in the other set.
+    ///
+    /// This is Kotlin's `subtract`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use kust::SetExt;
+    ///
+    /// let all = HashSet::from([1, 2, 3, 4]);
+    /// let done = HashSet::from([2, 4, 6]);
+    ///
+    /// assert_eq!(all.subtract(&done), HashSet::from([1, 3]));
+    /// ```
+    #[must_use]
+    fn subtract(self, other: &Self) -> Self;
+
+    /// Returns this set without the elements returned by the specified iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use kust::SetExt;
+    ///
+    /// let letters = BTreeSet::from_iter('a'..='e').subtract_all("bad".chars());
+    /// assert_eq!(letters, BTreeSet::from(['c', 'e']));
+    /// ```
+    #[must_use]
+    fn subtract_all<I>(self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>;
+
+    /// Returns the elements present in exactly one of this set and the other set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use kust::SetExt;
+    ///
+    /// let before = HashSet::from(["a.rs", "b.rs", "c.rs"]);
+    /// let after = HashSet::from(["b.rs", "c.rs", "d.rs"]);
+    ///
+    /// assert_eq!(before.symmetric_difference_with(after), HashSet::from(["a.rs", "d.rs"]));
+    /// ```
+    #[must_use]
+    fn symmetric_difference_with(self, other: Self) -> Self;
+
+    /// Returns the elements present in exactly one of this set and the elements returned by the specified iterator.
+    ///
+    /// Elements returned several times by the iterator count once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use kust::SetExt;
+    ///
+    /// let set = BTreeSet::from([1, 2, 3]).symmetric_difference_with_all([3, 4, 4]);
+    /// assert_eq!(set, BTreeSet::from([1, 2, 4]));
+    /// ```
+    #[must_use]
+    fn symmetric_difference_with_all<I>(self, iter: I) -> Self
+    where
+        Self: FromIterator<T>,
+        I: IntoIterator<Item = T>;
+}
+
+macro_rules! set_ext_methods {
+    () => {
+        #[inline]
+        fn union_with(mut self, other: Self) -> Self {
+            self.extend(other);
+            self
+        }
+
+        #[inline]
+        fn union_with_all<I>(mut self, iter: I) -> Self
+        where
+            I: IntoIterator<Item = T>,
+        {
+            self.extend(iter);
+            self
+        }
+
+        #[inline]
+        fn intersect_with(mut self, other: &Self) -> Self {
+            self.retain(|element| other.contains(element));
+            self
+        }
+
+        #[inline]
+        fn intersect_with_all<I>(self, iter: I) -> Self
+        where
+            Self: FromIterator<T>,
+            I: IntoIterator<Item = T>,
+        {
+            self.intersect_with(&iter.into_iter().collect())
+        }
+
+        #[inline]
+        fn subtract(mut self, other: &Self) -> Self {
+            self.retain(|element| !other.contains(element));
+            self
+        }
+
+        #[inline]
+        fn subtract_all<I>(mut self, iter: I) -> Self
+        where
+            I: IntoIterator<Item = T>,
+        {
+            for element in iter {
+                self.remove(&element);
+            }
+            self
+        }
+
+        #[inline]
+        fn symmetric_difference_with(mut self, other: Self) -> Self {
+            for element in other {
+                if !self.remove(&element) {
+                    self.insert(element);
+                }
+            }
+            self
+        }
+
+        #[inline]
+        fn symmetric_difference_with_all<I>(self, iter: I) -> Self
+        where
+            Self: FromIterator<T>,
+            I: IntoIterator<Item = T>,
+        {
+            self.symmetric_difference_with(iter.into_iter().collect())
+        }
+    };
+}
+
+impl<T, S> SetExt<T> for HashSet<T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    set_ext_methods!();
+}
+
+impl<T> SetExt<T> for BTreeSet<T>
+where
+    T: Ord,
+{
+    set_ext_methods!();
+}
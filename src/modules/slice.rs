@@ -0,0 +1,358 @@
+use std::borrow::Borrow;
+use std::cmp::{Ordering, Reverse};
+use std::ops::Range;
+
+/// Kotlin-style extensions for slices.
+pub trait SliceExt<T> {
+    /// Returns an iterator over sliding windows of the given size, as sub-slices, starting every `step` elements.
+    ///
+    /// If `partial` is `true`, the windows at the end, which are smaller than `size`, are also returned, as long as
+    /// they are not empty. This is Kotlin's `windowed`; unlike [`slice::windows`], it supports a step and partial
+    /// windows. See also [`IteratorExt::windowed`](crate::IteratorExt::windowed) for any iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `step` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let text = ['k', 'o', 't', 'l', 'i', 'n'];
+    ///
+    /// let windows: Vec<&[char]> = text.windowed(4, 2, false).collect();
+    /// assert_eq!(windows, [&['k', 'o', 't', 'l'][..], &['t', 'l', 'i', 'n']]);
+    ///
+    /// let windows: Vec<&[char]> = text.windowed(4, 3, true).collect();
+    /// assert_eq!(windows, [&['k', 'o', 't', 'l'][..], &['l', 'i', 'n']]);
+    /// ```
+    fn windowed(&self, size: usize, step: usize, partial: bool) -> SliceWindows<'_, T>;
+
+    /// Binary searches this sorted slice for the element whose key, as returned by the specified function, equals
+    /// `key`, following Kotlin's convention for the result.
+    ///
+    /// Returns the index of a matching element if found, otherwise the inverted insertion point `-(insertion point) -
+    /// 1`, which is always negative. The slice must be sorted by the key. This is Kotlin's `binarySearchBy`; see
+    /// [`slice::binary_search_by_key`] for the [`Result`]-based equivalent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is larger than `isize::MAX`, which is only possible for slices of zero-sized types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let versions = [(1, "alpha"), (3, "beta"), (4, "rc")];
+    ///
+    /// assert_eq!(versions.binary_search_by_key_or_insertion(&3, |&(v, _)| v), 1);
+    /// assert_eq!(versions.binary_search_by_key_or_insertion(&2, |&(v, _)| v), -2);
+    /// assert_eq!(versions.binary_search_by_key_or_insertion(&9, |&(v, _)| v), -4);
+    /// ```
+    #[must_use]
+    fn binary_search_by_key_or_insertion<K, F>(&self, key: &K, f: F) -> isize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Binary searches the sub-slice in `range` of this slice with the specified comparator, which returns the
+    /// ordering of each element relative to the target.
+    ///
+    /// Only the elements in `range` must be sorted. The returned indices, both for a match and for the insertion
+    /// point, are relative to the whole slice. This is Kotlin's `binarySearch` with `fromIndex` and `toIndex`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of the slice, or its start is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// // The header and the footer are not part of the sorted data.
+    /// let data = [99, 2, 4, 8, 16, 0];
+    ///
+    /// assert_eq!(data.binary_search_range(1..5, |n| n.cmp(&8)), Ok(3));
+    /// assert_eq!(data.binary_search_range(1..5, |n| n.cmp(&5)), Err(3));
+    /// assert_eq!(data.binary_search_range(1..5, |n| n.cmp(&100)), Err(5));
+    /// ```
+    fn binary_search_range<F>(&self, range: Range<usize>, compare: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering;
+
+    /// Binary searches this sorted slice for the element whose key, as borrowed by the specified selector, equals
+    /// `key`.
+    ///
+    /// Unlike [`slice::binary_search_by_key`], the selector returns a reference and the searched key may be a
+    /// borrowed form of it, such as a `&str` for a [`String`] field, so no dummy element or owned key has to be
+    /// constructed. The slice must be sorted by the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// struct User { name: String, age: u32 }
+    ///
+    /// let users = [
+    ///     User { name: "Anna".to_string(), age: 31 },
+    ///     User { name: "Linda".to_string(), age: 27 },
+    ///     User { name: "Mike".to_string(), age: 35 },
+    /// ];
+    ///
+    /// let found = users.binary_search_by_selector("Linda", |user| &user.name);
+    /// assert_eq!(found.map(|i| users[i].age), Ok(27));
+    /// assert_eq!(users.binary_search_by_selector("Bob", |user| &user.name), Err(1));
+    /// ```
+    fn binary_search_by_selector<K, Q, F>(&self, key: &Q, selector: F) -> Result<usize, usize>
+    where
+        K: Borrow<Q> + ?Sized,
+        Q: Ord + ?Sized,
+        F: FnMut(&T) -> &K;
+
+    /// Sorts the slice in descending order, in place.
+    ///
+    /// The sort is stable. This is Kotlin's `sortDescending`, without the error-prone `|a, b| b.cmp(a)` closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let mut scores = [72, 95, 88];
+    /// scores.sort_descending();
+    ///
+    /// assert_eq!(scores, [95, 88, 72]);
+    /// ```
+    fn sort_descending(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the slice in descending order of the key returned by the specified function, in place.
+    ///
+    /// The sort is stable, so elements with equal keys keep their order. This is Kotlin's `sortByDescending`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let mut files = [("a.txt", 120), ("b.txt", 4096), ("c.txt", 120)];
+    /// files.sort_by_key_descending(|&(_, size)| size);
+    ///
+    /// assert_eq!(files, [("b.txt", 4096), ("a.txt", 120), ("c.txt", 120)]);
+    /// ```
+    fn sort_by_key_descending<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sorts the slice in descending order, in place, without preserving the order of equal elements.
+    ///
+    /// See [`slice::sort_unstable`] for when this is preferable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let mut letters = ['k', 'o', 't', 'l', 'i', 'n'];
+    /// letters.sort_unstable_descending();
+    ///
+    /// assert_eq!(letters, ['t', 'o', 'n', 'l', 'k', 'i']);
+    /// ```
+    fn sort_unstable_descending(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the slice in descending order of the key returned by the specified function, in place, without
+    /// preserving the order of elements with equal keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let mut words = ["fig", "banana", "kiwi"];
+    /// words.sort_unstable_by_key_descending(|w| w.len());
+    ///
+    /// assert_eq!(words, ["banana", "kiwi", "fig"]);
+    /// ```
+    fn sort_unstable_by_key_descending<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Returns references to the elements at the given indices, in the order of the indices.
+    ///
+    /// Indices may repeat. This is Kotlin's `slice` with a list of indices; see [`try_select`](SliceExt::try_select)
+    /// for a non-panicking version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let row = ["id", "name", "email", "age"];
+    ///
+    /// assert_eq!(row.select(&[1, 3]), [&"name", &"age"]);
+    /// assert_eq!(row.select([0, 0]), [&"id", &"id"]);
+    /// ```
+    #[must_use]
+    fn select<I>(&self, indices: I) -> Vec<&T>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<usize>;
+
+    /// Returns references to the elements at the given indices, in the order of the indices, or [`None`] if any index
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::SliceExt;
+    ///
+    /// let primes = [2, 3, 5, 7];
+    ///
+    /// assert_eq!(primes.try_select(&[3, 0]), Some(vec![&7, &2]));
+    /// assert_eq!(primes.try_select(&[1, 4]), None);
+    /// ```
+    #[must_use]
+    fn try_select<I>(&self, indices: I) -> Option<Vec<&T>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<usize>;
+}
+
+impl<T> SliceExt<T> for [T] {
+    #[inline]
+    fn windowed(&self, size: usize, step: usize, partial: bool) -> SliceWindows<'_, T> {
+        assert!(size != 0 && step != 0, "window size and step must be non-zero");
+        SliceWindows { slice: self, size, step, partial, start: 0 }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn binary_search_by_key_or_insertion<K, F>(&self, key: &K, f: F) -> isize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        // Only slices of zero-sized types can be longer than `isize::MAX`. Up to it, `-1 - insertion` can't overflow.
+        match self.binary_search_by_key(key, f) {
+            Ok(index) => isize::try_from(index),
+            Err(insertion) => isize::try_from(insertion).map(|insertion| -1 - insertion),
+        }
+        .expect("the result doesn't fit in an isize")
+    }
+
+    #[inline]
+    fn binary_search_range<F>(&self, range: Range<usize>, compare: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let start = range.start;
+        self[range].binary_search_by(compare).map(|i| start + i).map_err(|i| start + i)
+    }
+
+    #[inline]
+    fn binary_search_by_selector<K, Q, F>(&self, key: &Q, mut selector: F) -> Result<usize, usize>
+    where
+        K: Borrow<Q> + ?Sized,
+        Q: Ord + ?Sized,
+        F: FnMut(&T) -> &K,
+    {
+        self.binary_search_by(|item| selector(item).borrow().cmp(key))
+    }
+
+    #[inline]
+    fn sort_descending(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| b.cmp(a));
+    }
+
+    #[inline]
+    fn sort_by_key_descending<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by_key(|item| Reverse(f(item)));
+    }
+
+    #[inline]
+    fn sort_unstable_descending(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(|a, b| b.cmp(a));
+    }
+
+    #[inline]
+    fn sort_unstable_by_key_descending<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_unstable_by_key(|item| Reverse(f(item)));
+    }
+
+    #[inline]
+    fn select<I>(&self, indices: I) -> Vec<&T>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<usize>,
+    {
+        indices.into_iter().map(|index| &self[*index.borrow()]).collect()
+    }
+
+    #[inline]
+    fn try_select<I>(&self, indices: I) -> Option<Vec<&T>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<usize>,
+    {
+        indices.into_iter().map(|index| self.get(*index.borrow())).collect()
+    }
+}
+
+/// An iterator over sliding windows of a slice, created by [`SliceExt::windowed`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SliceWindows<'a, T> {
+    slice: &'a [T],
+    size: usize,
+    step: usize,
+    partial: bool,
+    start: usize,
+}
+
+impl<'a, T> Iterator for SliceWindows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start >= self.slice.len() {
+            return None;
+        }
+        let end = self.start.saturating_add(self.size);
+        let window = if end <= self.slice.len() {
+            &self.slice[self.start..end]
+        } else if self.partial {
+            &self.slice[self.start..]
+        } else {
+            self.start = self.slice.len();
+            return None;
+        };
+        self.start = self.start.saturating_add(self.step);
+        Some(window)
+    }
+}
@@ -0,0 +1,87 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::PanicError;
+
+/// Calls the specified function with the given receiver as an argument and returns the result of the function.
+///
+/// Use `with` to group operations on a value that isn't in a method-call position. This is the free-function
+/// counterpart of [`using`](crate::ScopeFunctions::using).
+///
+/// # Examples
+///
+/// ```
+/// use kust::with;
+///
+/// let numbers = vec![3, 1, 4, 1, 5];
+///
+/// let summary = with(&numbers, |n| {
+///     let min = n.iter().min().unwrap();
+///     let max = n.iter().max().unwrap();
+///     format!("{} numbers, from {min} to {max}", n.len())
+/// });
+///
+/// assert_eq!(summary, "5 numbers, from 1 to 5");
+/// ```
+#[inline]
+pub fn with<T, F, R>(receiver: T, f: F) -> R
+where
+    F: FnOnce(T) -> R,
+{
+    f(receiver)
+}
+
+/// Calls the specified function and returns its result.
+///
+/// Use `run` to evaluate a block of statements where an expression is required, such as when initializing a variable
+/// with complex logic, without resorting to an immediately-invoked closure.
+///
+/// # Examples
+///
+/// ```
+/// use kust::run;
+///
+/// let input = "name=Mike;age=34";
+///
+/// let age: u32 = run(|| {
+///     let field = input.split(';').find(|f| f.starts_with("age="))?;
+///     field["age=".len()..].parse().ok()
+/// })
+/// .unwrap_or(0);
+///
+/// assert_eq!(age, 34);
+/// ```
+#[inline]
+pub fn run<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    f()
+}
+
+/// Calls the specified function and returns its result, or the caught panic if it panics.
+///
+/// This is Kotlin's `runCatching` for panics, wrapping [`std::panic::catch_unwind`]. The function is assumed to be
+/// [unwind safe](std::panic::UnwindSafe): if it panics, any state it was modifying may be left inconsistent. Note that
+/// the panic hook still runs, printing the panic message to stderr by default.
+///
+/// # Examples
+///
+/// ```
+/// use kust::run_catching;
+///
+/// fn third_party_parse(input: &str) -> u32 {
+///     input.parse().expect("invalid number")
+/// }
+///
+/// assert_eq!(run_catching(|| third_party_parse("42")).ok(), Some(42));
+///
+/// let error = run_catching(|| third_party_parse("forty-two")).unwrap_err();
+/// assert!(error.message().unwrap().starts_with("invalid number"));
+/// ```
+#[inline]
+pub fn run_catching<F, R>(f: F) -> Result<R, PanicError>
+where
+    F: FnOnce() -> R,
+{
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(PanicError::new)
+}
@@ -0,0 +1,1591 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::iter;
+use std::str::ParseBoolError;
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Declares or implements the `to_*_or_none` and `to_*_or_none_radix` functions of [`StringExt`] for integer types.
+macro_rules! parse_int_fns {
+    (declare; $($ty:ident => $name:ident, $radix_name:ident;)*) => {$(
+        #[doc = concat!("Parses this string as an [`", stringify!($ty), "`] in base 10, or returns `None` if invalid.")]
+        ///
+        /// This is Kotlin's `toIntOrNull` family, with the syntax of [`from_str_radix`](i32::from_str_radix): an
+        /// optional `+` sign, or `-` for signed types, followed by digits, without surrounding whitespace, which can
+        /// be removed first with [`str::trim_ascii`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use kust::StringExt;
+        ///
+        #[doc = concat!("assert_eq!(\"+42\".", stringify!($name), "(), Some(42));")]
+        #[doc = concat!("assert_eq!(\"4 2\".", stringify!($name), "(), None);")]
+        #[doc = concat!("assert_eq!(\" 42\\n\".trim_ascii().", stringify!($name), "(), Some(42));")]
+        /// ```
+        #[must_use]
+        fn $name(&self) -> Option<$ty>;
+
+        #[doc = concat!("Parses this string as an [`", stringify!($ty), "`] in the specified base, or returns `None`.")]
+        ///
+        /// Digits above 9 are the letters `a` to `z`, in either case. This is Kotlin's `toIntOrNull(radix)` family.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `radix` is not between 2 and 36, inclusive.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use kust::StringExt;
+        ///
+        #[doc = concat!("assert_eq!(\"2A\".", stringify!($radix_name), "(16), Some(42));")]
+        #[doc = concat!("assert_eq!(\"101010\".", stringify!($radix_name), "(2), Some(42));")]
+        #[doc = concat!("assert_eq!(\"2A\".", stringify!($radix_name), "(10), None);")]
+        /// ```
+        #[must_use]
+        fn $radix_name(&self, radix: u32) -> Option<$ty>;
+    )*};
+    (implement; $($ty:ident => $name:ident, $radix_name:ident;)*) => {$(
+        #[inline]
+        fn $name(&self) -> Option<$ty> {
+            self.parse::<$ty>().ok()
+        }
+
+        #[inline]
+        #[track_caller]
+        fn $radix_name(&self, radix: u32) -> Option<$ty> {
+            $ty::from_str_radix(self, radix).ok()
+        }
+    )*};
+}
+
+/// Kotlin-style extensions for string slices.
+pub trait StringExt {
+    /// Returns this string with the minimal common indentation of its non-blank lines removed, and the first and last
+    /// lines removed if they are blank.
+    ///
+    /// This is Kotlin's `trimIndent`, for writing multi-line string literals indented along with the surrounding code.
+    /// Indentation is counted in whitespace characters, so tabs and spaces count the same, and lines are always
+    /// joined with `\n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let query = "
+    ///     SELECT name
+    ///       FROM users
+    ///
+    ///      WHERE id = 1
+    /// ";
+    ///
+    /// assert_eq!(query.trim_indent(), "SELECT name\n  FROM users\n\n WHERE id = 1");
+    /// ```
+    #[must_use]
+    fn trim_indent(&self) -> String;
+
+    /// Returns this string with the leading whitespace and the `|` margin prefix removed from each line that has it,
+    /// and the first and last lines removed if they are blank.
+    ///
+    /// This is Kotlin's `trimMargin`; see [`trim_margin_with`](StringExt::trim_margin_with) for another prefix.
+    /// Lines without the prefix are kept as-is, and lines are always joined with `\n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let usage = "
+    ///     |Usage: kust [OPTIONS]
+    ///     |
+    ///     |  -h, --help  Print help
+    /// ";
+    ///
+    /// assert_eq!(usage.trim_margin(), "Usage: kust [OPTIONS]\n\n  -h, --help  Print help");
+    /// ```
+    #[must_use]
+    fn trim_margin(&self) -> String;
+
+    /// Returns this string with the leading whitespace and the specified margin prefix removed from each line that has
+    /// it, and the first and last lines removed if they are blank.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix is empty or only contains whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let poem = "
+    ///     > Roses are red,
+    ///     >   violets are blue
+    /// ";
+    ///
+    /// assert_eq!(poem.trim_margin_with(">"), " Roses are red,\n   violets are blue");
+    /// ```
+    #[must_use]
+    fn trim_margin_with(&self, prefix: &str) -> String;
+
+    /// Returns this string padded at the start with the specified character, to a length of at least `width`
+    /// characters.
+    ///
+    /// This is Kotlin's `padStart`. The length is counted in [`char`]s, not bytes, so that any text pads correctly as
+    /// long as each character is displayed in one column. Graphemes made of several characters, such as letters with
+    /// combining accents, and wide characters, such as CJK ideographs, still pad to a different display width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("7".pad_start(3, '0'), "007");
+    /// assert_eq!("€5".pad_start(4, '.'), "..€5");
+    /// assert_eq!("1234".pad_start(3, '0'), "1234");
+    /// ```
+    #[must_use]
+    fn pad_start(&self, width: usize, pad: char) -> String;
+
+    /// Returns this string padded at the end with the specified character, to a length of at least `width`
+    /// characters.
+    ///
+    /// This is Kotlin's `padEnd`. As with [`pad_start`](StringExt::pad_start), the length is counted in [`char`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let row = ["Name", "Age"].map(|cell| cell.pad_end(6, ' ')).concat();
+    /// assert_eq!(row, "Name  Age   ");
+    /// ```
+    #[must_use]
+    fn pad_end(&self, width: usize, pad: char) -> String;
+
+    /// Returns this string without the specified prefix if it starts with it, otherwise the whole string.
+    ///
+    /// This is Kotlin's `removePrefix`, in place of `s.strip_prefix(prefix).unwrap_or(s)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("v1.2.0".remove_prefix("v"), "1.2.0");
+    /// assert_eq!("1.2.0".remove_prefix("v"), "1.2.0");
+    /// ```
+    #[must_use]
+    fn remove_prefix(&self, prefix: &str) -> &str;
+
+    /// Returns this string without the specified suffix if it ends with it, otherwise the whole string.
+    ///
+    /// This is Kotlin's `removeSuffix`, in place of `s.strip_suffix(suffix).unwrap_or(s)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let url = "https://example.com/docs/";
+    /// assert_eq!(url.remove_prefix("https://").remove_suffix("/"), "example.com/docs");
+    /// ```
+    #[must_use]
+    fn remove_suffix(&self, suffix: &str) -> &str;
+
+    /// Returns this string without the specified prefix and suffix if it both starts and ends with them, otherwise
+    /// the whole string.
+    ///
+    /// Like Kotlin's `removeSurrounding`, the prefix and suffix must not overlap, so nothing is removed from a string
+    /// shorter than both of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("\"quoted\"".remove_surrounding("\"", "\""), "quoted");
+    /// assert_eq!("(unbalanced".remove_surrounding("(", ")"), "(unbalanced");
+    /// assert_eq!("\"".remove_surrounding("\"", "\""), "\"");
+    /// ```
+    #[must_use]
+    fn remove_surrounding(&self, prefix: &str, suffix: &str) -> &str;
+
+    /// Returns this string with its first character converted to titlecase, borrowing it if it is unchanged.
+    ///
+    /// This is Kotlin's `replaceFirstChar { it.titlecase() }`, formerly `capitalize`. Titlecase is usually the same as
+    /// uppercase, except for characters such as the digraph `ǆ`, titlecased to `ǅ` rather than `Ǆ`, and ligatures
+    /// such as `ﬁ`, titlecased to `Fi` rather than `FI`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("kotlin".capitalize(), "Kotlin");
+    /// assert_eq!("ǆungla".capitalize(), "ǅungla");
+    /// assert_eq!("ßtraße".capitalize(), "Sstraße");
+    /// assert!(matches!("Rust".capitalize(), Cow::Borrowed("Rust")));
+    /// ```
+    #[must_use]
+    fn capitalize(&self) -> Cow<'_, str>;
+
+    /// Returns this string with its first character converted to lowercase, borrowing it if it is unchanged.
+    ///
+    /// This is Kotlin's `replaceFirstChar { it.lowercase() }`, formerly `decapitalize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("ToString".decapitalize(), "toString");
+    /// assert!(matches!("name".decapitalize(), Cow::Borrowed("name")));
+    /// ```
+    #[must_use]
+    fn decapitalize(&self) -> Cow<'_, str>;
+
+    /// Returns the part of this string before the first occurrence of the delimiter, or the whole string if it
+    /// doesn't contain it.
+    ///
+    /// This is Kotlin's `substringBefore`; see [`substring_before_or`](StringExt::substring_before_or) for another
+    /// fallback.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("key=value=1".substring_before("="), "key");
+    /// assert_eq!("flag".substring_before("="), "flag");
+    /// ```
+    #[must_use]
+    fn substring_before(&self, delimiter: &str) -> &str;
+
+    /// Returns the part of this string before the first occurrence of the delimiter, or the specified fallback if it
+    /// doesn't contain it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("https://example.com".substring_before_or("://", "http"), "https");
+    /// assert_eq!("example.com".substring_before_or("://", "http"), "http");
+    /// ```
+    #[must_use]
+    fn substring_before_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str;
+
+    /// Returns the part of this string after the first occurrence of the delimiter, or the whole string if it doesn't
+    /// contain it.
+    ///
+    /// This is Kotlin's `substringAfter`; see [`substring_after_or`](StringExt::substring_after_or) for another
+    /// fallback.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("key=value=1".substring_after("="), "value=1");
+    /// assert_eq!("flag".substring_after("="), "flag");
+    /// ```
+    #[must_use]
+    fn substring_after(&self, delimiter: &str) -> &str;
+
+    /// Returns the part of this string after the first occurrence of the delimiter, or the specified fallback if it
+    /// doesn't contain it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("flag".substring_after_or("=", "true"), "true");
+    /// ```
+    #[must_use]
+    fn substring_after_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str;
+
+    /// Returns the part of this string before the last occurrence of the delimiter, or the whole string if it doesn't
+    /// contain it.
+    ///
+    /// This is Kotlin's `substringBeforeLast`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("archive.tar.gz".substring_before_last("."), "archive.tar");
+    /// assert_eq!("/usr/local/bin".substring_before_last("/"), "/usr/local");
+    /// ```
+    #[must_use]
+    fn substring_before_last(&self, delimiter: &str) -> &str;
+
+    /// Returns the part of this string before the last occurrence of the delimiter, or the specified fallback if it
+    /// doesn't contain it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("README".substring_before_last_or("/", "."), ".");
+    /// ```
+    #[must_use]
+    fn substring_before_last_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str;
+
+    /// Returns the part of this string after the last occurrence of the delimiter, or the whole string if it doesn't
+    /// contain it.
+    ///
+    /// This is Kotlin's `substringAfterLast`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("archive.tar.gz".substring_after_last("."), "gz");
+    /// assert_eq!("/usr/local/bin".substring_after_last("/"), "bin");
+    /// ```
+    #[must_use]
+    fn substring_after_last(&self, delimiter: &str) -> &str;
+
+    /// Returns the part of this string after the last occurrence of the delimiter, or the specified fallback if it
+    /// doesn't contain it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("photo.jpeg".substring_after_last_or(".", ""), "jpeg");
+    /// assert_eq!("Makefile".substring_after_last_or(".", ""), "");
+    /// ```
+    #[must_use]
+    fn substring_after_last_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str;
+
+    /// Returns `true` if this string is empty or only contains whitespace.
+    ///
+    /// This is Kotlin's `isBlank`, in place of `s.trim().is_empty()`. Whitespace is as defined by
+    /// [`char::is_whitespace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert!("".is_blank());
+    /// assert!(" \t\n".is_blank());
+    /// assert!(!" x ".is_blank());
+    /// ```
+    #[must_use]
+    fn is_blank(&self) -> bool;
+
+    /// Returns `true` if this string contains a character other than whitespace.
+    ///
+    /// This is Kotlin's `isNotBlank`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let names = ["Mike", "  ", "", "Linda"];
+    /// let valid: Vec<_> = names.into_iter().filter(|name| name.is_not_blank()).collect();
+    /// assert_eq!(valid, ["Mike", "Linda"]);
+    /// ```
+    #[must_use]
+    fn is_not_blank(&self) -> bool;
+
+    /// Returns this string if it is not blank, or the string returned by the specified function otherwise.
+    ///
+    /// This is Kotlin's `ifBlank`. The function may return a borrowed or an owned string, so the result is a [`Cow`].
+    /// See [`CollectionExt::if_empty`](crate::CollectionExt::if_empty) for the equivalent for empty strings only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let input = "   ";
+    /// assert_eq!(input.if_blank(|| "anonymous"), "anonymous");
+    /// assert_eq!("Mike".if_blank(|| "anonymous"), "Mike");
+    ///
+    /// let id = 7;
+    /// assert_eq!("".if_blank(|| format!("user-{id}")), "user-7");
+    /// ```
+    #[must_use]
+    fn if_blank<'a, F, D>(&'a self, default: F) -> Cow<'a, str>
+    where
+        F: FnOnce() -> D,
+        D: Into<Cow<'a, str>>;
+
+    /// Returns the longest prefix shared by this string and the other string, as a slice of this string.
+    ///
+    /// This is Kotlin's `commonPrefixWith`. The prefix always ends at a character boundary of both strings. If
+    /// `ignore_case` is `true`, case is ignored like with [`equals_ignore_case`](StringExt::equals_ignore_case).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("/home/mike/docs".common_prefix_with("/home/mila", false), "/home/mi");
+    /// assert_eq!("Crème".common_prefix_with("crèpe", true), "Crè");
+    /// assert_eq!("Straße".common_prefix_with("STRASSE", true), "Straße");
+    /// assert_eq!("kotlin".common_prefix_with("rust", false), "");
+    /// ```
+    #[must_use]
+    fn common_prefix_with(&self, other: &str, ignore_case: bool) -> &str;
+
+    /// Returns the longest suffix shared by this string and the other string, as a slice of this string.
+    ///
+    /// This is Kotlin's `commonSuffixWith`; see [`common_prefix_with`](StringExt::common_prefix_with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("testing".common_suffix_with("running", false), "ing");
+    /// assert_eq!("report.PDF".common_suffix_with("scan.pdf", true), ".PDF");
+    /// ```
+    #[must_use]
+    fn common_suffix_with(&self, other: &str, ignore_case: bool) -> &str;
+
+    /// Returns this string with the specified indent prepended to each line.
+    ///
+    /// This is Kotlin's `prependIndent`: blank lines shorter than the indent are replaced by it, other blank lines are
+    /// kept as-is, and lines are always joined with `\n`. See
+    /// [`prepend_indent_skipping_blank`](StringExt::prepend_indent_skipping_blank) to keep all blank lines as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let body = "let x = 1;\nx + 1";
+    /// let block = format!("{{\n{}\n}}", body.prepend_indent("    "));
+    ///
+    /// assert_eq!(block, "{\n    let x = 1;\n    x + 1\n}");
+    /// ```
+    #[must_use]
+    fn prepend_indent(&self, indent: &str) -> String;
+
+    /// Returns this string with the specified indent prepended to each line that isn't blank.
+    ///
+    /// Unlike with [`prepend_indent`](StringExt::prepend_indent), blank lines are kept as-is, so that no trailing
+    /// whitespace is added to them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let yaml = "name: kust\n\nversion: 1\n";
+    ///
+    /// assert_eq!(yaml.prepend_indent_skipping_blank("  "), "  name: kust\n\n  version: 1\n");
+    /// assert_eq!(yaml.prepend_indent("  "), "  name: kust\n  \n  version: 1\n  ");
+    /// ```
+    #[must_use]
+    fn prepend_indent_skipping_blank(&self, indent: &str) -> String;
+
+    /// Returns an iterator over the parts of this string separated by any of the specified delimiters.
+    ///
+    /// This is Kotlin's `split` with several delimiters. At each position, the earliest occurrence of any delimiter is
+    /// used, preferring the first one in the slice when several start at the same position. If `ignore_case` is
+    /// `true`, characters are compared like with [`common_prefix_with`](StringExt::common_prefix_with). If `limit`
+    /// isn't 0, at most `limit` parts are returned, the last one containing the rest of the string, unsplit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let parts: Vec<_> = "a,b;c, d".split_any(&[", ", ",", ";"], false, 0).collect();
+    /// assert_eq!(parts, ["a", "b", "c", "d"]);
+    ///
+    /// let parts: Vec<_> = "key=value=more".split_any(&["="], false, 2).collect();
+    /// assert_eq!(parts, ["key", "value=more"]);
+    ///
+    /// let parts: Vec<_> = "1 AND 2 and 3".split_any(&[" and ", " or "], true, 0).collect();
+    /// assert_eq!(parts, ["1", "2", "3"]);
+    /// ```
+    fn split_any<'a>(&'a self, delimiters: &'a [&'a str], ignore_case: bool, limit: usize) -> SplitAny<'a>;
+
+    /// Returns an iterator over consecutive parts of this string of `size` characters each, the last one possibly
+    /// smaller.
+    ///
+    /// This is Kotlin's `chunked`. The size is counted in [`char`]s, so the string is never split inside a character;
+    /// see [`chunked_graphemes`](StringExt::chunked_graphemes) to also keep graphemes made of several characters
+    /// whole.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let groups: Vec<_> = "DE89370400440532013000".chunked(4).collect();
+    /// assert_eq!(groups.join(" "), "DE89 3704 0044 0532 0130 00");
+    ///
+    /// assert_eq!("αβγδε".chunked(2).collect::<Vec<_>>(), ["αβ", "γδ", "ε"]);
+    /// ```
+    fn chunked(&self, size: usize) -> StrWindows<'_>;
+
+    /// Returns an iterator over sliding windows of `size` characters of this string, starting every `step`
+    /// characters.
+    ///
+    /// If `partial` is `true`, the windows at the end, which are smaller than `size`, are also returned, as long as
+    /// they are not empty. This is Kotlin's `windowed`, like [`SliceExt::windowed`](crate::SliceExt::windowed), with
+    /// sizes counted in [`char`]s; see [`windowed_graphemes`](StringExt::windowed_graphemes) to count graphemes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `step` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let trigrams: Vec<_> = "kotlin".windowed(3, 1, false).collect();
+    /// assert_eq!(trigrams, ["kot", "otl", "tli", "lin"]);
+    ///
+    /// let windows: Vec<_> = "kotlin".windowed(4, 3, true).collect();
+    /// assert_eq!(windows, ["kotl", "lin"]);
+    /// ```
+    fn windowed(&self, size: usize, step: usize, partial: bool) -> StrWindows<'_>;
+
+    /// Returns an iterator over consecutive parts of this string of `size` extended grapheme clusters each, the last
+    /// one possibly smaller.
+    ///
+    /// This is [`chunked`](StringExt::chunked) counting what users perceive as characters, such as letters with
+    /// combining accents or emoji sequences. Requires the `unicode` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let text = "e\u{301}te\u{301}"; // "été" with combining accents
+    ///
+    /// assert_eq!(text.chunked_graphemes(2).collect::<Vec<_>>(), ["e\u{301}t", "e\u{301}"]);
+    /// assert_eq!(text.chunked(2).collect::<Vec<_>>(), ["e\u{301}", "te", "\u{301}"]);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn chunked_graphemes(&self, size: usize) -> StrWindows<'_>;
+
+    /// Returns an iterator over sliding windows of `size` extended grapheme clusters of this string, starting every
+    /// `step` grapheme clusters.
+    ///
+    /// This is [`windowed`](StringExt::windowed) counting what users perceive as characters. Requires the `unicode`
+    /// feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `step` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let flags = "🇫🇷🇩🇪🇮🇹";
+    /// assert_eq!(flags.windowed_graphemes(2, 1, false).collect::<Vec<_>>(), ["🇫🇷🇩🇪", "🇩🇪🇮🇹"]);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn windowed_graphemes(&self, size: usize, step: usize, partial: bool) -> StrWindows<'_>;
+
+    /// Returns `true` if this string is equal to the other string, ignoring case.
+    ///
+    /// This is Kotlin's `equals` with `ignoreCase`, extended beyond ASCII unlike [`str::eq_ignore_ascii_case`].
+    /// Characters are compared after converting them to uppercase and then to lowercase, which approximates Unicode
+    /// case folding, so that for example `ß` equals `SS`. The strings are compared without allocating lowercase
+    /// copies. All the functions of this trait ignoring case follow these rules, and only match whole characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert!("Kotlin".equals_ignore_case("KOTLIN"));
+    /// assert!("Straße".equals_ignore_case("STRASSE"));
+    /// assert!("ΟΔΟΣ".equals_ignore_case("οδος"));
+    /// assert!(!"kotlin".equals_ignore_case("rust"));
+    /// ```
+    #[must_use]
+    fn equals_ignore_case(&self, other: &str) -> bool;
+
+    /// Compares this string with the other string lexicographically, ignoring case.
+    ///
+    /// This is Kotlin's `compareTo` with `ignoreCase`; see [`equals_ignore_case`](StringExt::equals_ignore_case) for
+    /// how characters are compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let mut names = ["bob", "Alice", "carol", "Bob"];
+    /// names.sort_by(|a, b| a.compare_ignore_case(b));
+    /// assert_eq!(names, ["Alice", "bob", "Bob", "carol"]);
+    /// ```
+    #[must_use]
+    fn compare_ignore_case(&self, other: &str) -> Ordering;
+
+    /// Returns `true` if this string starts with the specified prefix, ignoring case.
+    ///
+    /// The prefix must end at a character boundary of this string, so `"ß"` starts with `"SS"` but not with `"S"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert!("Content-Type: text/html".starts_with_ignore_case("content-type:"));
+    /// assert!(!"Content-Length: 5".starts_with_ignore_case("content-type:"));
+    /// assert!(!"ßa".starts_with_ignore_case("s"));
+    /// ```
+    #[must_use]
+    fn starts_with_ignore_case(&self, prefix: &str) -> bool;
+
+    /// Returns `true` if this string contains the specified string, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert!("Error: Connection REFUSED".contains_ignore_case("refused"));
+    /// assert!("anything".contains_ignore_case(""));
+    /// assert!("Fußball".contains_ignore_case("SSB"));
+    /// assert!(!"Error: timeout".contains_ignore_case("refused"));
+    /// ```
+    #[must_use]
+    fn contains_ignore_case(&self, needle: &str) -> bool;
+
+    /// Returns the number of non-overlapping occurrences of the specified string or character in this string.
+    ///
+    /// Occurrences are found from the start, like with [`str::matches`]; an empty needle occurs at every character
+    /// boundary. See [`count_overlapping`](StringExt::count_overlapping) to also count overlapping occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("a, b, c".count_occurrences(", "), 2);
+    /// assert_eq!("aaaa".count_occurrences("aa"), 2);
+    /// assert_eq!("naïve".count_occurrences('ï'), 1);
+    /// ```
+    #[must_use]
+    fn count_occurrences<N: Needle>(&self, needle: N) -> usize;
+
+    /// Returns the number of occurrences of the specified string or character in this string, including overlapping
+    /// ones.
+    ///
+    /// Each character boundary where the needle starts counts once, so an empty needle occurs at every character
+    /// boundary, like with [`count_occurrences`](StringExt::count_occurrences). Characters never overlap, so for them
+    /// this is the same as `count_occurrences`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("aaaa".count_overlapping("aa"), 3);
+    /// assert_eq!("ACGCGCG".count_overlapping("CGC"), 2);
+    /// assert_eq!("abc".count_overlapping(""), 4);
+    /// assert_eq!("abca".count_overlapping('a'), 2);
+    /// ```
+    #[must_use]
+    fn count_overlapping<N: Needle>(&self, needle: N) -> usize;
+
+    parse_int_fns! {
+        declare;
+        i8 => to_i8_or_none, to_i8_or_none_radix;
+        i16 => to_i16_or_none, to_i16_or_none_radix;
+        i32 => to_i32_or_none, to_i32_or_none_radix;
+        i64 => to_i64_or_none, to_i64_or_none_radix;
+        i128 => to_i128_or_none, to_i128_or_none_radix;
+        isize => to_isize_or_none, to_isize_or_none_radix;
+        u8 => to_u8_or_none, to_u8_or_none_radix;
+        u16 => to_u16_or_none, to_u16_or_none_radix;
+        u32 => to_u32_or_none, to_u32_or_none_radix;
+        u64 => to_u64_or_none, to_u64_or_none_radix;
+        u128 => to_u128_or_none, to_u128_or_none_radix;
+        usize => to_usize_or_none, to_usize_or_none_radix;
+    }
+
+    /// Parses this string as an [`f32`], returning `None` if it isn't a valid one.
+    ///
+    /// This is Kotlin's `toFloatOrNull`, with the syntax of [`f32::from_str`](std::str::FromStr::from_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("2.5".to_f32_or_none(), Some(2.5));
+    /// assert_eq!("2,5".to_f32_or_none(), None);
+    /// ```
+    #[must_use]
+    fn to_f32_or_none(&self) -> Option<f32>;
+
+    /// Parses this string as an [`f64`], returning `None` if it isn't a valid one.
+    ///
+    /// This is Kotlin's `toDoubleOrNull`, with the syntax of [`f64::from_str`](std::str::FromStr::from_str), which
+    /// accepts a leading `+`, exponents, `inf` and `NaN`, but no surrounding whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("-1.5e3".to_f64_or_none(), Some(-1500.0));
+    /// assert_eq!(" 1.5".to_f64_or_none(), None);
+    /// assert_eq!(" 1.5".trim_ascii().to_f64_or_none(), Some(1.5));
+    /// ```
+    #[must_use]
+    fn to_f64_or_none(&self) -> Option<f64>;
+
+    /// Parses this string as a [`bool`], accepting only `true` and `false`.
+    ///
+    /// This is Kotlin's `toBooleanStrict`, returning the error instead of throwing it. Unlike Kotlin's lenient
+    /// `toBoolean`, any other string is an error, rather than `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("true".to_bool_strict(), Ok(true));
+    /// assert!("True".to_bool_strict().is_err());
+    /// ```
+    fn to_bool_strict(&self) -> Result<bool, ParseBoolError>;
+
+    /// Parses this string as a [`bool`], accepting only `true` and `false`, or returns `None`.
+    ///
+    /// This is Kotlin's `toBooleanStrictOrNull`; see [`to_bool_or_none`](StringExt::to_bool_or_none) to also accept
+    /// other common spellings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("false".to_bool_strict_or_none(), Some(false));
+    /// assert_eq!("no".to_bool_strict_or_none(), None);
+    /// ```
+    #[must_use]
+    fn to_bool_strict_or_none(&self) -> Option<bool>;
+
+    /// Parses this string as a [`bool`] leniently, or returns `None`.
+    ///
+    /// `true`, `yes` and `1` are `true`, and `false`, `no` and `0` are `false`, ignoring ASCII case. Any other string,
+    /// including one with surrounding whitespace, is `None`, so that typos in configuration files are not silently
+    /// read as `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("YES".to_bool_or_none(), Some(true));
+    /// assert_eq!("0".to_bool_or_none(), Some(false));
+    /// assert_eq!("ture".to_bool_or_none(), None);
+    /// ```
+    #[must_use]
+    fn to_bool_or_none(&self) -> Option<bool>;
+
+    /// Returns this string shortened to at most `max_chars` characters, ending with `…` if it was shortened.
+    ///
+    /// This is [`truncate_with`](StringExt::truncate_with) with the `…` suffix, which counts as one character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// assert_eq!("Hello, world!".ellipsize(8), "Hello, …");
+    /// assert_eq!("Hello".ellipsize(8), "Hello");
+    /// ```
+    #[must_use]
+    fn ellipsize(&self, max_chars: usize) -> Cow<'_, str>;
+
+    /// Returns this string shortened to at most `max_chars` characters including the suffix, which is only added if
+    /// it was shortened, borrowing the string if it already fits.
+    ///
+    /// Characters are counted as [`char`]s, so the string is never cut inside a character, although it can be cut
+    /// inside a grapheme made of several characters. If the suffix alone is longer than `max_chars`, the result is
+    /// the start of the suffix. See [`truncate_at_word_with`](StringExt::truncate_at_word_with) to avoid cutting
+    /// words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let line = "ERROR connection refused by 10.0.0.1";
+    /// assert_eq!(line.truncate_with(20, " [...]"), "ERROR connecti [...]");
+    /// assert_eq!("Grüße".truncate_with(4, "."), "Grü.");
+    /// ```
+    #[must_use]
+    fn truncate_with(&self, max_chars: usize, suffix: &str) -> Cow<'_, str>;
+
+    /// Returns this string shortened to at most `max_chars` characters including the suffix, cut at a word boundary
+    /// if possible.
+    ///
+    /// Like [`truncate_with`](StringExt::truncate_with), but the string is cut after the last whole word that fits,
+    /// and whitespace before the suffix is removed. A first word too long to fit is cut anyway, even after leading
+    /// whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::StringExt;
+    ///
+    /// let title = "The quick brown fox jumps";
+    /// assert_eq!(title.truncate_at_word_with(16, "…"), "The quick brown…");
+    /// assert_eq!(title.truncate_at_word_with(12, "…"), "The quick…");
+    /// assert_eq!("Supercalifragilistic".truncate_at_word_with(6, "…"), "Super…");
+    /// assert_eq!("   hello world".truncate_at_word_with(5, "…"), "   h…");
+    /// ```
+    #[must_use]
+    fn truncate_at_word_with(&self, max_chars: usize, suffix: &str) -> Cow<'_, str>;
+}
+
+impl StringExt for str {
+    #[inline]
+    fn trim_indent(&self) -> String {
+        let indent = lines(self).filter(|line| line.is_not_blank()).map(indent_width).min().unwrap_or(0);
+        reindent(self, |line| Some(drop_chars(line, indent)))
+    }
+
+    #[inline]
+    fn trim_margin(&self) -> String {
+        self.trim_margin_with("|")
+    }
+
+    #[inline]
+    #[track_caller]
+    fn trim_margin_with(&self, prefix: &str) -> String {
+        assert!(prefix.is_not_blank(), "the margin prefix must not be blank");
+        reindent(self, |line| line.trim_start().strip_prefix(prefix))
+    }
+
+    #[inline]
+    fn pad_start(&self, width: usize, pad: char) -> String {
+        let padding = width.saturating_sub(self.chars().count());
+        let mut result = String::with_capacity(self.len() + padding * pad.len_utf8());
+        result.extend(iter::repeat_n(pad, padding));
+        result.push_str(self);
+        result
+    }
+
+    #[inline]
+    fn pad_end(&self, width: usize, pad: char) -> String {
+        let padding = width.saturating_sub(self.chars().count());
+        let mut result = String::with_capacity(self.len() + padding * pad.len_utf8());
+        result.push_str(self);
+        result.extend(iter::repeat_n(pad, padding));
+        result
+    }
+
+    #[inline]
+    fn remove_prefix(&self, prefix: &str) -> &str {
+        self.strip_prefix(prefix).unwrap_or(self)
+    }
+
+    #[inline]
+    fn remove_suffix(&self, suffix: &str) -> &str {
+        self.strip_suffix(suffix).unwrap_or(self)
+    }
+
+    #[inline]
+    fn remove_surrounding(&self, prefix: &str, suffix: &str) -> &str {
+        if self.len() >= prefix.len() + suffix.len() && self.starts_with(prefix) && self.ends_with(suffix) {
+            &self[prefix.len()..self.len() - suffix.len()]
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn capitalize(&self) -> Cow<'_, str> {
+        replace_first_char(self, push_titlecase)
+    }
+
+    #[inline]
+    fn decapitalize(&self) -> Cow<'_, str> {
+        replace_first_char(self, |result, c| result.extend(c.to_lowercase()))
+    }
+
+    #[inline]
+    fn substring_before(&self, delimiter: &str) -> &str {
+        self.substring_before_or(delimiter, self)
+    }
+
+    #[inline]
+    fn substring_before_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str {
+        self.split_once(delimiter).map_or(fallback, |(before, _)| before)
+    }
+
+    #[inline]
+    fn substring_after(&self, delimiter: &str) -> &str {
+        self.substring_after_or(delimiter, self)
+    }
+
+    #[inline]
+    fn substring_after_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str {
+        self.split_once(delimiter).map_or(fallback, |(_, after)| after)
+    }
+
+    #[inline]
+    fn substring_before_last(&self, delimiter: &str) -> &str {
+        self.substring_before_last_or(delimiter, self)
+    }
+
+    #[inline]
+    fn substring_before_last_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str {
+        self.rsplit_once(delimiter).map_or(fallback, |(before, _)| before)
+    }
+
+    #[inline]
+    fn substring_after_last(&self, delimiter: &str) -> &str {
+        self.substring_after_last_or(delimiter, self)
+    }
+
+    #[inline]
+    fn substring_after_last_or<'a>(&'a self, delimiter: &str, fallback: &'a str) -> &'a str {
+        self.rsplit_once(delimiter).map_or(fallback, |(_, after)| after)
+    }
+
+    #[inline]
+    fn is_blank(&self) -> bool {
+        self.chars().all(char::is_whitespace)
+    }
+
+    #[inline]
+    fn is_not_blank(&self) -> bool {
+        !self.is_blank()
+    }
+
+    #[inline]
+    fn if_blank<'a, F, D>(&'a self, default: F) -> Cow<'a, str>
+    where
+        F: FnOnce() -> D,
+        D: Into<Cow<'a, str>>,
+    {
+        if self.is_blank() {
+            default().into()
+        } else {
+            Cow::Borrowed(self)
+        }
+    }
+
+    #[inline]
+    fn common_prefix_with(&self, other: &str, ignore_case: bool) -> &str {
+        if !ignore_case {
+            let len: usize =
+                self.chars().zip(other.chars()).take_while(|(a, b)| a == b).map(|(a, _)| a.len_utf8()).sum();
+            return &self[..len];
+        }
+        let mut len = 0;
+        for ((a, a_end), (b, b_end)) in fold_case_forward(self).zip(fold_case_forward(other)) {
+            if a != b {
+                break;
+            }
+            if let (Some(end), Some(_)) = (a_end, b_end) {
+                len = end;
+            }
+        }
+        &self[..len]
+    }
+
+    #[inline]
+    fn common_suffix_with(&self, other: &str, ignore_case: bool) -> &str {
+        if !ignore_case {
+            let len: usize = self
+                .chars()
+                .rev()
+                .zip(other.chars().rev())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a.len_utf8())
+                .sum();
+            return &self[self.len() - len..];
+        }
+        let mut start = self.len();
+        for ((a, a_start), (b, b_start)) in fold_case_backward(self).zip(fold_case_backward(other)) {
+            if a != b {
+                break;
+            }
+            if let (Some(index), Some(_)) = (a_start, b_start) {
+                start = index;
+            }
+        }
+        &self[start..]
+    }
+
+    #[inline]
+    fn prepend_indent(&self, indent: &str) -> String {
+        let lines: Vec<_> = lines(self)
+            .map(|line| {
+                if !line.is_blank() {
+                    Cow::Owned(format!("{indent}{line}"))
+                } else if line.chars().count() < indent.chars().count() {
+                    Cow::Borrowed(indent)
+                } else {
+                    Cow::Borrowed(line)
+                }
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    #[inline]
+    fn prepend_indent_skipping_blank(&self, indent: &str) -> String {
+        let lines: Vec<_> = lines(self)
+            .map(|line| if line.is_blank() { Cow::Borrowed(line) } else { Cow::Owned(format!("{indent}{line}")) })
+            .collect();
+        lines.join("\n")
+    }
+
+    #[inline]
+    fn split_any<'a>(&'a self, delimiters: &'a [&'a str], ignore_case: bool, limit: usize) -> SplitAny<'a> {
+        SplitAny {
+            string: self,
+            delimiters,
+            ignore_case,
+            remaining: if limit == 0 { usize::MAX } else { limit },
+            start: 0,
+            search: 0,
+            occurrences: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn chunked(&self, size: usize) -> StrWindows<'_> {
+        assert!(size != 0, "chunk size must be non-zero");
+        StrWindows { string: self, size, step: size, partial: true, start: 0, unit: Unit::Char }
+    }
+
+    #[inline]
+    fn windowed(&self, size: usize, step: usize, partial: bool) -> StrWindows<'_> {
+        assert!(size != 0 && step != 0, "window size and step must be non-zero");
+        StrWindows { string: self, size, step, partial, start: 0, unit: Unit::Char }
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn chunked_graphemes(&self, size: usize) -> StrWindows<'_> {
+        assert!(size != 0, "chunk size must be non-zero");
+        StrWindows { string: self, size, step: size, partial: true, start: 0, unit: Unit::Grapheme }
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn windowed_graphemes(&self, size: usize, step: usize, partial: bool) -> StrWindows<'_> {
+        assert!(size != 0 && step != 0, "window size and step must be non-zero");
+        StrWindows { string: self, size, step, partial, start: 0, unit: Unit::Grapheme }
+    }
+
+    #[inline]
+    fn equals_ignore_case(&self, other: &str) -> bool {
+        self == other || fold_case(self).eq(fold_case(other))
+    }
+
+    #[inline]
+    fn compare_ignore_case(&self, other: &str) -> Ordering {
+        fold_case(self).cmp(fold_case(other))
+    }
+
+    #[inline]
+    fn starts_with_ignore_case(&self, prefix: &str) -> bool {
+        match_ignore_case(self, prefix).is_some()
+    }
+
+    #[inline]
+    fn contains_ignore_case(&self, needle: &str) -> bool {
+        needle.is_empty() || self.char_indices().any(|(index, _)| self[index..].starts_with_ignore_case(needle))
+    }
+
+    #[inline]
+    fn count_occurrences<N: Needle>(&self, needle: N) -> usize {
+        needle.count_in(self)
+    }
+
+    #[inline]
+    fn count_overlapping<N: Needle>(&self, needle: N) -> usize {
+        needle.count_overlapping_in(self)
+    }
+
+    parse_int_fns! {
+        implement;
+        i8 => to_i8_or_none, to_i8_or_none_radix;
+        i16 => to_i16_or_none, to_i16_or_none_radix;
+        i32 => to_i32_or_none, to_i32_or_none_radix;
+        i64 => to_i64_or_none, to_i64_or_none_radix;
+        i128 => to_i128_or_none, to_i128_or_none_radix;
+        isize => to_isize_or_none, to_isize_or_none_radix;
+        u8 => to_u8_or_none, to_u8_or_none_radix;
+        u16 => to_u16_or_none, to_u16_or_none_radix;
+        u32 => to_u32_or_none, to_u32_or_none_radix;
+        u64 => to_u64_or_none, to_u64_or_none_radix;
+        u128 => to_u128_or_none, to_u128_or_none_radix;
+        usize => to_usize_or_none, to_usize_or_none_radix;
+    }
+
+    #[inline]
+    fn to_f32_or_none(&self) -> Option<f32> {
+        self.parse().ok()
+    }
+
+    #[inline]
+    fn to_f64_or_none(&self) -> Option<f64> {
+        self.parse().ok()
+    }
+
+    #[inline]
+    fn to_bool_strict(&self) -> Result<bool, ParseBoolError> {
+        self.parse()
+    }
+
+    #[inline]
+    fn to_bool_strict_or_none(&self) -> Option<bool> {
+        self.parse().ok()
+    }
+
+    #[inline]
+    fn to_bool_or_none(&self) -> Option<bool> {
+        const TRUE: [&str; 3] = ["true", "yes", "1"];
+        const FALSE: [&str; 3] = ["false", "no", "0"];
+        if TRUE.iter().any(|value| self.eq_ignore_ascii_case(value)) {
+            Some(true)
+        } else if FALSE.iter().any(|value| self.eq_ignore_ascii_case(value)) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn ellipsize(&self, max_chars: usize) -> Cow<'_, str> {
+        self.truncate_with(max_chars, "…")
+    }
+
+    #[inline]
+    fn truncate_with(&self, max_chars: usize, suffix: &str) -> Cow<'_, str> {
+        truncate(self, max_chars, suffix, false)
+    }
+
+    #[inline]
+    fn truncate_at_word_with(&self, max_chars: usize, suffix: &str) -> Cow<'_, str> {
+        truncate(self, max_chars, suffix, true)
+    }
+}
+
+/// A string or character to search for, accepted by [`count_occurrences`](StringExt::count_occurrences) and
+/// [`count_overlapping`](StringExt::count_overlapping).
+///
+/// Implemented for `char`, `&str` and `&String`. This trait is sealed and cannot be implemented outside this crate.
+pub trait Needle: private::Sealed {
+    #[doc(hidden)]
+    fn count_in(self, haystack: &str) -> usize;
+
+    #[doc(hidden)]
+    fn count_overlapping_in(self, haystack: &str) -> usize;
+}
+
+impl Needle for char {
+    #[inline]
+    fn count_in(self, haystack: &str) -> usize {
+        haystack.matches(self).count()
+    }
+
+    #[inline]
+    fn count_overlapping_in(self, haystack: &str) -> usize {
+        self.count_in(haystack)
+    }
+}
+
+impl Needle for &str {
+    #[inline]
+    fn count_in(self, haystack: &str) -> usize {
+        haystack.matches(self).count()
+    }
+
+    #[inline]
+    fn count_overlapping_in(self, haystack: &str) -> usize {
+        let mut count = 0;
+        let mut start = 0;
+        while let Some(index) = haystack[start..].find(self) {
+            count += 1;
+            // Search again from the next character, to also find an occurrence starting inside this one.
+            match haystack[start + index..].chars().next() {
+                Some(c) => start += index + c.len_utf8(),
+                None => break,
+            }
+        }
+        count
+    }
+}
+
+impl Needle for &String {
+    #[inline]
+    fn count_in(self, haystack: &str) -> usize {
+        self.as_str().count_in(haystack)
+    }
+
+    #[inline]
+    fn count_overlapping_in(self, haystack: &str) -> usize {
+        self.as_str().count_overlapping_in(haystack)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for char {}
+    impl Sealed for &str {}
+    impl Sealed for &String {}
+}
+
+/// An iterator over the parts of a string separated by any of several delimiters, created by
+/// [`split_any`](StringExt::split_any).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitAny<'a> {
+    string: &'a str,
+    delimiters: &'a [&'a str],
+    ignore_case: bool,
+    /// The number of parts left to return, 0 once the last one was returned.
+    remaining: usize,
+    start: usize,
+    /// Where to search for the next delimiter, after `start` if the last delimiter found was empty.
+    search: usize,
+    /// The next occurrence of each delimiter found so far, or `None` once a delimiter doesn't occur anymore, so that
+    /// each delimiter only scans the string once. Empty until the first search.
+    occurrences: Vec<Option<(usize, usize)>>,
+}
+
+impl SplitAny<'_> {
+    /// Returns the byte range of the earliest occurrence at or after `search` of any of the delimiters, preferring the
+    /// first one when several start at the same position.
+    fn next_delimiter(&mut self) -> Option<(usize, usize)> {
+        if self.occurrences.is_empty() {
+            self.occurrences = self.delimiters.iter().map(|delimiter| self.find_from_search(delimiter)).collect();
+        }
+        for (index, delimiter) in self.delimiters.iter().enumerate() {
+            if matches!(self.occurrences[index], Some((start, _)) if start < self.search) {
+                self.occurrences[index] = self.find_from_search(delimiter);
+            }
+        }
+        self.occurrences.iter().flatten().copied().min_by_key(|&(start, _)| start)
+    }
+
+    /// Returns the byte range of the first occurrence of the delimiter at or after `search`.
+    fn find_from_search(&self, delimiter: &str) -> Option<(usize, usize)> {
+        let (s, from) = (self.string, self.search);
+        if from > s.len() {
+            return None;
+        }
+        if !self.ignore_case {
+            return s[from..].find(delimiter).map(|index| (from + index, from + index + delimiter.len()));
+        }
+        (from..=s.len())
+            .filter(|&index| s.is_char_boundary(index))
+            .find_map(|start| match_ignore_case(&s[start..], delimiter).map(|len| (start, start + len)))
+    }
+}
+
+impl<'a> Iterator for SplitAny<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let delimiter = match self.remaining {
+            0 => None,
+            _ => self.next_delimiter(),
+        };
+        let Some((delimiter_start, delimiter_end)) = delimiter else {
+            self.remaining = 0;
+            return Some(&self.string[self.start..]);
+        };
+        let part = &self.string[self.start..delimiter_start];
+        self.start = delimiter_end;
+        // Move past an empty delimiter, or it would be found again at the same position.
+        self.search = match self.string[delimiter_end..].chars().next() {
+            Some(c) if delimiter_start == delimiter_end => delimiter_end + c.len_utf8(),
+            None if delimiter_start == delimiter_end => self.string.len() + 1,
+            _ => delimiter_end,
+        };
+        Some(part)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining.min(1), Some(self.remaining.min(self.string.len().saturating_add(2))))
+    }
+}
+
+/// An iterator over parts of a string counted in characters or graphemes, created by
+/// [`chunked`](StringExt::chunked), [`windowed`](StringExt::windowed) and their grapheme variants.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct StrWindows<'a> {
+    string: &'a str,
+    size: usize,
+    step: usize,
+    partial: bool,
+    start: usize,
+    unit: Unit,
+}
+
+/// What the size and step of [`StrWindows`] are counted in.
+#[derive(Debug, Clone, Copy)]
+enum Unit {
+    Char,
+    #[cfg(feature = "unicode")]
+    Grapheme,
+}
+
+impl Unit {
+    /// Returns the byte offset `n` units after `from`, or the end of the string if it is shorter, and the number of
+    /// units skipped.
+    fn advance(self, s: &str, from: usize, n: usize) -> (usize, usize) {
+        let rest = &s[from..];
+        let (offset, count) = match self {
+            Unit::Char => advance_by(rest.char_indices().map(|(index, _)| index), rest.len(), n),
+            #[cfg(feature = "unicode")]
+            Unit::Grapheme => advance_by(rest.grapheme_indices(true).map(|(index, _)| index), rest.len(), n),
+        };
+        (from + offset, count)
+    }
+}
+
+fn advance_by(offsets: impl Iterator<Item = usize>, len: usize, n: usize) -> (usize, usize) {
+    let mut count = 0;
+    for offset in offsets {
+        if count == n {
+            return (offset, count);
+        }
+        count += 1;
+    }
+    (len, count)
+}
+
+impl<'a> Iterator for StrWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (end, count) = self.unit.advance(self.string, self.start, self.size);
+        if count == 0 || count < self.size && !self.partial {
+            self.start = self.string.len();
+            return None;
+        }
+        let window = &self.string[self.start..end];
+        self.start = self.unit.advance(self.string, self.start, self.step).0;
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each window starts at a different byte.
+        (0, Some(self.string.len() - self.start))
+    }
+}
+
+/// Splits the string into lines like Kotlin's `lines`, on `\n` and `\r\n`, including a last empty line after a final
+/// line break.
+fn lines(s: &str) -> impl Iterator<Item = &str> {
+    s.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+fn indent_width(line: &str) -> usize {
+    line.chars().take_while(|c| c.is_whitespace()).count()
+}
+
+fn drop_chars(s: &str, n: usize) -> &str {
+    s.char_indices().nth(n).map_or("", |(index, _)| &s[index..])
+}
+
+/// Returns the characters of the string converted to uppercase and then to lowercase, to compare strings ignoring
+/// case.
+fn fold_case(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(fold_char)
+}
+
+fn fold_char(c: char) -> impl DoubleEndedIterator<Item = char> {
+    c.to_uppercase().flat_map(char::to_lowercase)
+}
+
+/// Returns the folded characters of the string like [`fold_case`], each with the byte offset of the end of its
+/// character if it is the last one that character folds to.
+fn fold_case_forward(s: &str) -> impl Iterator<Item = (char, Option<usize>)> + '_ {
+    s.char_indices().flat_map(|(index, c)| {
+        let (count, end) = (fold_char(c).count(), index + c.len_utf8());
+        fold_char(c).enumerate().map(move |(i, folded)| (folded, (i + 1 == count).then_some(end)))
+    })
+}
+
+/// Returns the folded characters of the string in reverse order, each with the byte offset of the start of its
+/// character if it is the last one of that character in this order.
+fn fold_case_backward(s: &str) -> impl Iterator<Item = (char, Option<usize>)> + '_ {
+    s.char_indices().rev().flat_map(|(index, c)| {
+        let count = fold_char(c).count();
+        fold_char(c).rev().enumerate().map(move |(i, folded)| (folded, (i + 1 == count).then_some(index)))
+    })
+}
+
+/// Returns the length in bytes of the start of `haystack` equal to `needle` ignoring case, ending at a character
+/// boundary of `haystack`.
+fn match_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    let mut needle = fold_case(needle).peekable();
+    let mut len = 0;
+    for c in haystack.chars() {
+        if needle.peek().is_none() {
+            break;
+        }
+        for folded in fold_char(c) {
+            if needle.next() != Some(folded) {
+                return None;
+            }
+        }
+        len += c.len_utf8();
+    }
+    needle.peek().is_none().then_some(len)
+}
+
+/// Removes the first and last lines if they are blank, and replaces each remaining line with the result of `cut`,
+/// or keeps it if that returns `None`, joining the lines with `\n`.
+fn reindent<'a>(s: &'a str, mut cut: impl FnMut(&'a str) -> Option<&'a str>) -> String {
+    let lines: Vec<_> = lines(s).collect();
+    let last = lines.len() - 1;
+    let kept: Vec<_> = lines
+        .into_iter()
+        .enumerate()
+        .filter(|&(index, line)| !((index == 0 || index == last) && line.is_blank()))
+        .map(|(_, line)| cut(line).unwrap_or(line))
+        .collect();
+    kept.join("\n")
+}
+
+/// Replaces the first character of the string with the characters pushed by `replace`, borrowing the string if they
+/// are the same.
+fn replace_first_char(s: &str, replace: impl FnOnce(&mut String, char)) -> Cow<'_, str> {
+    let Some(first) = s.chars().next() else {
+        return Cow::Borrowed(s);
+    };
+    let (first, rest) = s.split_at(first.len_utf8());
+    let mut result = String::with_capacity(s.len());
+    replace(&mut result, first.chars().next().unwrap());
+    if result == first {
+        return Cow::Borrowed(s);
+    }
+    result.push_str(rest);
+    Cow::Owned(result)
+}
+
+/// Pushes the titlecase mapping of the character, which std doesn't provide.
+///
+/// It only differs from the uppercase mapping for the characters below, from `UnicodeData.txt` and
+/// `SpecialCasing.txt`.
+fn push_titlecase(result: &mut String, c: char) {
+    let titlecase = match c {
+        // Latin digraphs
+        '\u{1C4}'..='\u{1C6}' => "\u{1C5}",
+        '\u{1C7}'..='\u{1C9}' => "\u{1C8}",
+        '\u{1CA}'..='\u{1CC}' => "\u{1CB}",
+        '\u{1F1}'..='\u{1F3}' => "\u{1F2}",
+        // Georgian Mkhedruli letters are their own titlecase
+        '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}' => {
+            result.push(c);
+            return;
+        }
+        // Latin and Armenian ligatures
+        'ß' => "Ss",
+        'ﬀ' => "Ff",
+        'ﬁ' => "Fi",
+        'ﬂ' => "Fl",
+        'ﬃ' => "Ffi",
+        'ﬄ' => "Ffl",
+        'ﬅ' | 'ﬆ' => "St",
+        'և' => "Եւ",
+        'ﬓ' => "Մն",
+        'ﬔ' => "Մե",
+        'ﬕ' => "Մի",
+        'ﬖ' => "Վն",
+        'ﬗ' => "Մխ",
+        // Greek letters with ypogegrammeni, titlecased with prosgegrammeni
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+            result.push(char::from_u32(c as u32 + 8).unwrap());
+            return;
+        }
+        '\u{1F88}'..='\u{1F8F}' | '\u{1F98}'..='\u{1F9F}' | '\u{1FA8}'..='\u{1FAF}' => {
+            result.push(c);
+            return;
+        }
+        '\u{1FB3}' | '\u{1FBC}' => "\u{1FBC}",
+        '\u{1FC3}' | '\u{1FCC}' => "\u{1FCC}",
+        '\u{1FF3}' | '\u{1FFC}' => "\u{1FFC}",
+        '\u{1FB2}' => "\u{1FBA}\u{345}",
+        '\u{1FB4}' => "\u{386}\u{345}",
+        '\u{1FC2}' => "\u{1FCA}\u{345}",
+        '\u{1FC4}' => "\u{389}\u{345}",
+        '\u{1FF2}' => "\u{1FFA}\u{345}",
+        '\u{1FF4}' => "\u{38F}\u{345}",
+        '\u{1FB7}' => "\u{391}\u{342}\u{345}",
+        '\u{1FC7}' => "\u{397}\u{342}\u{345}",
+        '\u{1FF7}' => "\u{3A9}\u{342}\u{345}",
+        _ => {
+            result.extend(c.to_uppercase());
+            return;
+        }
+    };
+    result.push_str(titlecase);
+}
+
+/// Shortens the string to at most `max_chars` characters including the suffix, cutting after the last whole word if
+/// `at_word` is `true`.
+fn truncate<'a>(s: &'a str, max_chars: usize, suffix: &str, at_word: bool) -> Cow<'a, str> {
+    if s.char_indices().nth(max_chars).is_none() {
+        return Cow::Borrowed(s);
+    }
+    let suffix_chars = suffix.chars().count();
+    if suffix_chars >= max_chars {
+        return Cow::Owned(suffix.chars().take(max_chars).collect());
+    }
+    // The string is longer than `max_chars`, so there is a character at the cut.
+    let (cut, next) = s.char_indices().nth(max_chars - suffix_chars).unwrap();
+    let mut kept = &s[..cut];
+    if at_word {
+        // Drop the partial word at the cut, unless only whitespace comes before it.
+        let words = if next.is_whitespace() {
+            kept
+        } else {
+            kept.rfind(char::is_whitespace).map_or("", |index| &kept[..index])
+        };
+        let words = words.trim_end();
+        kept = if words.is_empty() { kept.trim_end() } else { words };
+    }
+    Cow::Owned([kept, suffix].concat())
+}
@@ -0,0 +1,279 @@
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ptr;
+
+/// Kotlin-style extensions for vectors.
+pub trait VecExt<T> {
+    /// Returns this vector with `element` appended.
+    ///
+    /// This is Kotlin's `plus`, allowing list arithmetic in expressions. Clone the vector first to keep the original.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// let base = vec!["--verbose"];
+    /// let args = base.clone().plus("--release");
+    ///
+    /// assert_eq!(args, ["--verbose", "--release"]);
+    /// assert_eq!(base, ["--verbose"]);
+    /// ```
+    #[must_use]
+    fn plus(self, element: T) -> Vec<T>;
+
+    /// Returns this vector with all `elements` appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// assert_eq!(vec![1, 2].plus_all(3..=5), [1, 2, 3, 4, 5]);
+    /// ```
+    #[must_use]
+    fn plus_all<I>(self, elements: I) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>;
+
+    /// Returns this vector without the first occurrence of `element`, if any.
+    ///
+    /// This is Kotlin's `minus`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// assert_eq!(vec![1, 2, 1, 3].minus(&1), [2, 1, 3]);
+    /// assert_eq!(vec![1, 2].minus(&9), [1, 2]);
+    /// ```
+    #[must_use]
+    fn minus(self, element: &T) -> Vec<T>
+    where
+        T: PartialEq;
+
+    /// Returns this vector without any occurrence of the given `elements`, which can be passed by value or by
+    /// reference.
+    ///
+    /// Each element of the vector is compared against each of the given elements, so this is best suited for small
+    /// sets of removed elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// let words = vec!["a", "quick", "fox", "and", "a", "dog"];
+    ///
+    /// assert_eq!(words.minus_all(["a", "and"]), ["quick", "fox", "dog"]);
+    /// ```
+    #[must_use]
+    fn minus_all<I>(self, elements: I) -> Vec<T>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+        T: PartialEq;
+
+    /// Transforms every element with the specified function, in place, without reallocating.
+    ///
+    /// If the function panics, the vector is left empty, and all its remaining elements are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// let mut names = vec!["mike".to_string(), "linda".to_string()];
+    /// names.map_in_place(|name| name.to_uppercase());
+    ///
+    /// assert_eq!(names, ["MIKE", "LINDA"]);
+    /// ```
+    fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnMut(T) -> T;
+
+    /// Transforms every element with the specified function into a vector of another type, reusing the allocation of
+    /// this vector if `U` has the same size and alignment as `T`.
+    ///
+    /// Otherwise, this falls back to `into_iter().map(f).collect()`, which allocates a new vector. If the function
+    /// panics, all elements, both mapped and not yet mapped, are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// let celsius: Vec<i32> = vec![-40, 0, 100];
+    /// let ptr = celsius.as_ptr() as usize;
+    /// let fahrenheit = celsius.map_in_place_to(|c| c as f32 * 1.8 + 32.0);
+    ///
+    /// assert_eq!(fahrenheit, [-40.0, 32.0, 212.0]);
+    /// assert_eq!(fahrenheit.as_ptr() as usize, ptr); // `i32` and `f32` have the same layout
+    /// ```
+    #[must_use]
+    fn map_in_place_to<U, F>(self, f: F) -> Vec<U>
+    where
+        F: FnMut(T) -> U;
+
+    /// Removes and returns the first element matching the specified predicate, or returns [`None`] if there is none.
+    ///
+    /// This replaces the `position` and `remove` two-step. The following elements are shifted, like
+    /// [`Vec::remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// let mut queue = vec![("low", 1), ("high", 2), ("high", 3)];
+    ///
+    /// assert_eq!(queue.remove_first_matching(|&(priority, _)| priority == "high"), Some(("high", 2)));
+    /// assert_eq!(queue, [("low", 1), ("high", 3)]);
+    /// assert_eq!(queue.remove_first_matching(|&(priority, _)| priority == "urgent"), None);
+    /// ```
+    fn remove_first_matching<P>(&mut self, predicate: P) -> Option<T>
+    where
+        P: FnMut(&T) -> bool;
+
+    /// Removes and returns the last element matching the specified predicate, or returns [`None`] if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kust::VecExt;
+    ///
+    /// let mut history = vec!["open a", "edit a", "open b", "edit b"];
+    ///
+    /// assert_eq!(history.remove_last_matching(|entry| entry.starts_with("open")), Some("open b"));
+    /// assert_eq!(history, ["open a", "edit a", "edit b"]);
+    /// ```
+    fn remove_last_matching<P>(&mut self, predicate: P) -> Option<T>
+    where
+        P: FnMut(&T) -> bool;
+}
+
+impl<T> VecExt<T> for Vec<T> {
+    #[inline]
+    fn plus(mut self, element: T) -> Vec<T> {
+        self.push(element);
+        self
+    }
+
+    #[inline]
+    fn plus_all<I>(mut self, elements: I) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.extend(elements);
+        self
+    }
+
+    #[inline]
+    fn minus(mut self, element: &T) -> Vec<T>
+    where
+        T: PartialEq,
+    {
+        if let Some(index) = self.iter().position(|e| e == element) {
+            self.remove(index);
+        }
+        self
+    }
+
+    #[inline]
+    fn minus_all<I>(mut self, elements: I) -> Vec<T>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+        T: PartialEq,
+    {
+        let removed: Vec<_> = elements.into_iter().collect();
+        self.retain(|e| !removed.iter().any(|r| r.borrow() == e));
+        self
+    }
+
+    #[inline]
+    fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnMut(T) -> T,
+    {
+        *self = mem::take(self).map_in_place_to(f);
+    }
+
+    fn map_in_place_to<U, F>(self, mut f: F) -> Vec<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        if mem::size_of::<T>() != mem::size_of::<U>() || mem::align_of::<T>() != mem::align_of::<U>() {
+            return self.into_iter().map(f).collect();
+        }
+
+        let mut vec = ManuallyDrop::new(self);
+        let mut guard = MapInPlaceGuard::<T, U> {
+            ptr: vec.as_mut_ptr(),
+            len: vec.len(),
+            capacity: vec.capacity(),
+            mapped: 0,
+            _marker: PhantomData,
+        };
+        while guard.mapped < guard.len {
+            // SAFETY: The element at `mapped` is initialized, and is read exactly once. Should `f` panic, the guard
+            // skips it when dropping the remaining elements. `U` has the same size and alignment as `T`, so the
+            // mapped value fits in its place.
+            unsafe {
+                let element = guard.ptr.add(guard.mapped);
+                let mapped = f(element.read());
+                element.cast::<U>().write(mapped);
+            }
+            guard.mapped += 1;
+        }
+
+        let guard = ManuallyDrop::new(guard);
+        // SAFETY: All `len` elements are now initialized values of `U`, and the allocation has the same layout for
+        // `capacity` elements of `U` as for `capacity` elements of `T`.
+        unsafe { Vec::from_raw_parts(guard.ptr.cast::<U>(), guard.len, guard.capacity) }
+    }
+
+    #[inline]
+    fn remove_first_matching<P>(&mut self, predicate: P) -> Option<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let index = self.iter().position(predicate)?;
+        Some(self.remove(index))
+    }
+
+    #[inline]
+    fn remove_last_matching<P>(&mut self, predicate: P) -> Option<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let index = self.iter().rposition(predicate)?;
+        Some(self.remove(index))
+    }
+}
+
+/// Drops the elements of a vector being mapped by [`map_in_place_to`](VecExt::map_in_place_to), and frees its
+/// allocation, should the mapping function panic.
+struct MapInPlaceGuard<T, U> {
+    ptr: *mut T,
+    len: usize,
+    capacity: usize,
+    mapped: usize,
+    _marker: PhantomData<U>,
+}
+
+impl<T, U> Drop for MapInPlaceGuard<T, U> {
+    fn drop(&mut self) {
+        // SAFETY: The first `mapped` elements were mapped to `U`, the element at `mapped` was moved into the
+        // panicking function, and the rest are still initialized values of `T`. The allocation has the same layout
+        // for `U` as for `T`, so the empty vector frees it correctly.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.cast::<U>(), self.mapped));
+            let rest = self.ptr.add(self.mapped + 1);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(rest, self.len - self.mapped - 1));
+            drop(Vec::from_raw_parts(self.ptr.cast::<U>(), 0, self.capacity));
+        }
+    }
+}
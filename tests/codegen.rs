@@ -0,0 +1,105 @@
+//! Checks that the scope functions are zero-cost: every `kust_<name>` function in `tests/codegen/` must compile to the
+//! same optimized machine code as its hand-written `manual_<name>` counterpart.
+//!
+//! The crate sources and the fixture are compiled together by `rustc` into assembly, which is then compared function by
+//! function, after removing comments and directives and renumbering local labels.
+
+#![cfg(all(target_arch = "x86_64", target_os = "linux"))]
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::{env, fs};
+
+#[test]
+fn scope_functions() {
+    check("scope_functions");
+}
+
+fn check(fixture: &str) {
+    let manifest = env!("CARGO_MANIFEST_DIR");
+    let source = fs::read_to_string(format!("{manifest}/tests/codegen/{fixture}.rs")).unwrap();
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("codegen");
+    fs::create_dir_all(&dir).unwrap();
+
+    let root = dir.join(format!("{fixture}.rs"));
+    let asm = dir.join(format!("{fixture}.s"));
+    fs::write(
+        &root,
+        format!(
+            "#![allow(dead_code, unused_imports)]\n\
+             #[path = \"{manifest}/src/modules/mod.rs\"]\nmod modules;\npub use modules::*;\n\
+             #[path = \"{manifest}/tests/codegen/{fixture}.rs\"]\npub mod fixture;\n"
+        ),
+    )
+    .unwrap();
+
+    let status = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+        .args(["--edition", "2021", "--crate-type", "lib", "--crate-name", "codegen"])
+        .args(["-C", "opt-level=3", "-C", "panic=abort", "-C", "codegen-units=1", "--emit", "asm", "-o"])
+        .arg(&asm)
+        .arg(&root)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile the {fixture} fixture");
+    let asm = fs::read_to_string(&asm).unwrap();
+
+    let names: Vec<_> = source
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub fn kust_"))
+        .map(|rest| &rest[..rest.find('(').unwrap()])
+        .collect();
+    assert!(!names.is_empty(), "no functions to compare in the {fixture} fixture");
+
+    let mismatched: Vec<_> = names
+        .iter()
+        .filter(|name| {
+            let kust = function(&asm, &format!("kust_{name}"));
+            let manual = function(&asm, &format!("manual_{name}"));
+            if kust != manual {
+                eprintln!("kust_{name}:\n{}\nmanual_{name}:\n{}\n", kust.join("\n"), manual.join("\n"));
+            }
+            kust != manual
+        })
+        .collect();
+    assert!(mismatched.is_empty(), "functions compiled differently from their manual counterparts: {mismatched:?}");
+}
+
+/// Returns the normalized instructions of the specified function, following aliases to functions LLVM merged.
+fn function(asm: &str, name: &str) -> Vec<String> {
+    let alias = format!("{name} = ");
+    if let Some(target) = asm.lines().find_map(|line| line.strip_prefix(&alias)) {
+        return function(asm, target.trim());
+    }
+
+    let start = format!("{name}:");
+    let mut lines = asm.lines().skip_while(|line| *line != start).skip(1);
+    let mut labels = HashMap::new();
+    let mut body = Vec::new();
+    for line in lines.by_ref().take_while(|line| !line.starts_with(".Lfunc_end")) {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() || line.starts_with(".cfi") || line.starts_with(".p2align") {
+            continue;
+        }
+        body.push(rename_labels(line, &mut labels));
+    }
+    assert!(!body.is_empty(), "function {name} not found in the assembly");
+    body
+}
+
+/// Replaces the local labels in the specified line with their order of appearance in the function.
+fn rename_labels(line: &str, labels: &mut HashMap<String, usize>) -> String {
+    let mut result = String::new();
+    let mut rest = line;
+    while let Some(index) = rest.find(".L") {
+        result.push_str(&rest[..index]);
+        rest = &rest[index + 2..];
+        let end = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+        let count = labels.len();
+        let number = *labels.entry(rest[..end].to_string()).or_insert(count);
+        result.push_str(&format!(".L{number}"));
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
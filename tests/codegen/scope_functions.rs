@@ -0,0 +1,162 @@
+// Pairs of functions compiled by `tests/codegen.rs`: each `kust_<name>` must compile to the same machine code as
+// `manual_<name>`, which does the same thing without the crate.
+//
+// Values taken by `self` are bound to a local first in the manual versions, as the method call does; mutating a
+// `mut` parameter in place instead can produce a different, but equivalent, function.
+
+use crate::{run, with, ScopeFunctions, ScopeFunctions2, ScopeFunctionsRef};
+
+#[no_mangle]
+pub fn kust_using(x: u32) -> u32 {
+    x.using(|x| x * 3 + 1)
+}
+
+#[no_mangle]
+pub fn manual_using(x: u32) -> u32 {
+    x * 3 + 1
+}
+
+#[no_mangle]
+pub fn kust_run(v: Vec<u32>) -> usize {
+    v.run(|v| v.len() + v.capacity())
+}
+
+#[no_mangle]
+pub fn manual_run(v: Vec<u32>) -> usize {
+    let v = v;
+    v.len() + v.capacity()
+}
+
+#[no_mangle]
+pub fn kust_also(x: u64, out: &mut u64) -> u64 {
+    x.also(|x| *out = x << 2)
+}
+
+#[no_mangle]
+pub fn manual_also(x: u64, out: &mut u64) -> u64 {
+    *out = x << 2;
+    x
+}
+
+#[no_mangle]
+pub fn kust_apply(v: Vec<u32>) -> Vec<u32> {
+    v.apply(|v| v.push(7))
+}
+
+#[no_mangle]
+pub fn manual_apply(v: Vec<u32>) -> Vec<u32> {
+    let mut v = v;
+    v.push(7);
+    v
+}
+
+#[no_mangle]
+pub fn kust_also_if(x: u32, condition: bool, out: &mut u32) -> u32 {
+    x.also_if(condition, |x| *out = *x)
+}
+
+#[no_mangle]
+pub fn manual_also_if(x: u32, condition: bool, out: &mut u32) -> u32 {
+    if condition {
+        *out = x;
+    }
+    x
+}
+
+#[no_mangle]
+pub fn kust_apply_if(x: i32) -> i32 {
+    x.apply_if(|x: &i32| *x < 0, |x| *x = -*x)
+}
+
+#[no_mangle]
+pub fn manual_apply_if(x: i32) -> i32 {
+    let mut x = x;
+    if x < 0 {
+        x = -x;
+    }
+    x
+}
+
+#[no_mangle]
+pub fn kust_take_if(x: u32) -> Option<u32> {
+    x.take_if(|x| *x > 3)
+}
+
+#[no_mangle]
+pub fn manual_take_if(x: u32) -> Option<u32> {
+    if x > 3 {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+#[no_mangle]
+pub fn kust_take_unless(x: u32) -> Option<u32> {
+    x.take_unless(|x| *x == 0)
+}
+
+#[no_mangle]
+pub fn manual_take_unless(x: u32) -> Option<u32> {
+    if x == 0 {
+        None
+    } else {
+        Some(x)
+    }
+}
+
+#[no_mangle]
+pub fn kust_using_ref(s: &String) -> usize {
+    s.using_ref(|s| s.len() * 2)
+}
+
+#[no_mangle]
+pub fn manual_using_ref(s: &String) -> usize {
+    s.len() * 2
+}
+
+#[no_mangle]
+pub fn kust_apply_mut(v: &mut Vec<u8>) -> &mut Vec<u8> {
+    v.apply_mut(|v| v.clear())
+}
+
+#[no_mangle]
+pub fn manual_apply_mut(v: &mut Vec<u8>) -> &mut Vec<u8> {
+    v.clear();
+    v
+}
+
+#[no_mangle]
+pub fn kust_using2(a: u32, b: u32) -> u32 {
+    (a, b).using2(|a, b| a.wrapping_mul(b))
+}
+
+#[no_mangle]
+pub fn manual_using2(a: u32, b: u32) -> u32 {
+    a.wrapping_mul(b)
+}
+
+#[no_mangle]
+pub fn kust_with(v: Vec<u32>) -> u32 {
+    with(v, |v| v.iter().sum())
+}
+
+#[no_mangle]
+pub fn manual_with(v: Vec<u32>) -> u32 {
+    let v = v;
+    v.iter().sum()
+}
+
+#[no_mangle]
+pub fn kust_standalone_run(x: u32, y: u32) -> u32 {
+    run(|| {
+        let sum = x + y;
+        sum / 2
+    })
+}
+
+#[no_mangle]
+pub fn manual_standalone_run(x: u32, y: u32) -> u32 {
+    let sum = x + y;
+    sum / 2
+}